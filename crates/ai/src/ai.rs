@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use takeiteasy_core::tile::Tile;
+#[cfg(feature = "entropy")]
+use takeiteasy_core::Game;
+use takeiteasy_core::{Board, Field};
+
+fn best_field(scores: &HashMap<Field, f64>) -> Field {
+    let mut best_field = scores.keys().next().unwrap();
+    let mut best_score = scores.get(best_field).unwrap();
+    for (field, score) in scores {
+        // ties go to the smallest field, so the choice does not depend on the
+        // hash map order
+        if score > best_score || (score == best_score && field < best_field) {
+            best_score = score;
+            best_field = field;
+        }
+    }
+    *best_field
+}
+
+pub struct AI {}
+
+impl AI {
+    #[allow(clippy::result_unit_err)]
+    pub fn new() -> Result<AI, ()> {
+        Ok(AI {})
+    }
+
+    fn field_scores(board: &Board, tile: Tile, depth: i32, threads: usize) -> HashMap<Field, f64> {
+        let fields: Vec<Field> = board.empty_fields().into_iter().collect();
+        let score = move |&field: &Field| {
+            let board_with_new_tile = board.place_tile_on_new_board(field, tile).unwrap();
+            (
+                field,
+                AI::estimated_score(&board_with_new_tile, depth).unwrap(),
+            )
+        };
+        // no thread for a single one, and none at all without the `parallel`
+        // feature, e.g. on wasm
+        if threads <= 1 || !cfg!(feature = "parallel") {
+            return fields.iter().map(score).collect();
+        }
+        let chunk_size = fields.len().div_ceil(threads).max(1);
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = fields
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || chunk.iter().map(score).collect::<Vec<_>>()))
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect()
+        })
+    }
+
+    // field the AI would place the tile on, an error for a full board, a tile
+    // on the board or a negative depth
+    #[allow(clippy::result_unit_err)]
+    pub fn best_field(board: &Board, tile: Tile, depth: i32) -> Result<Field, ()> {
+        AI::best_field_threaded(board, tile, depth, 1)
+    }
+
+    // like `best_field`, with the empty fields evaluated on several threads
+    #[allow(clippy::result_unit_err)]
+    pub fn best_field_threaded(
+        board: &Board,
+        tile: Tile,
+        depth: i32,
+        threads: usize,
+    ) -> Result<Field, ()> {
+        if depth < 0 || board.is_full() || board.tiles.values().any(|t| *t == Some(tile)) {
+            return Err(());
+        }
+        Ok(best_field(&AI::field_scores(board, tile, depth, threads)))
+    }
+
+    #[cfg(feature = "entropy")]
+    pub fn play_game(depth: i32, print: bool) -> u32 {
+        let mut game = Game::new();
+        while !game.finished() {
+            let scores = AI::field_scores(&game.board, game.current_tile.unwrap(), depth, 1);
+            assert!(game.place_tile(best_field(&scores)).is_ok());
+            if print {
+                println!("{}", game.board);
+            }
+        }
+        let score = game.board.score();
+        if print {
+            println!("score: {}", score);
+        }
+        score
+    }
+
+    fn eval_position(board: &Board) -> Result<f64, ()> {
+        if board.is_full() {
+            return Ok(board.score() as f64);
+        }
+        Ok(board.max_score() as f64) // TODO: better estimate
+    }
+
+    // an error for a negative depth
+    #[allow(clippy::result_unit_err)]
+    pub fn estimated_score(board: &Board, iterations: i32) -> Result<f64, ()> {
+        AI::estimated_score_until(board, iterations, &|| false).ok_or(())
+    }
+
+    // like `estimated_score`, but gives up with None once `stopped` returns
+    // true, which is asked before every position above depth 0. None also
+    // for a negative depth.
+    pub fn estimated_score_until(
+        board: &Board,
+        iterations: i32,
+        stopped: &dyn Fn() -> bool,
+    ) -> Option<f64> {
+        if iterations < 0 {
+            return None;
+        }
+        if iterations == 0 || board.is_full() {
+            return AI::eval_position(board).ok();
+        }
+        if stopped() {
+            return None;
+        }
+
+        // calc more depth levels
+        // one level/iteration means the average of all remaining tiles on all empty fields
+        let mut total_score = 0.0;
+        for tile in board.remaining_tiles() {
+            let mut scores: HashMap<Field, f64> = HashMap::new();
+            for field in board.empty_fields() {
+                let new_board = board.place_tile_on_new_board(field, tile).unwrap();
+                let score = AI::estimated_score_until(&new_board, iterations - 1, stopped)?;
+                scores.insert(field, score);
+            }
+            let best_score = scores.get(&best_field(&scores)).unwrap();
+            total_score += best_score;
+        }
+        let avg_score = total_score / board.remaining_tiles().len() as f64;
+        Some(avg_score)
+    }
+}
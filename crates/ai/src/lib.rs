@@ -0,0 +1,8 @@
+// Search and evaluation on top of `takeiteasy-core`, re-exported by the
+// `takeiteasy` crate.
+
+pub mod ai;
+pub mod oracle;
+pub mod protocol;
+mod search;
+pub mod solver;
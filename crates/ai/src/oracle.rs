@@ -0,0 +1,45 @@
+use crate::search::Search;
+use std::collections::HashSet;
+use takeiteasy_core::rules::RuleSet;
+use takeiteasy_core::tile::Tile;
+use takeiteasy_core::Board;
+
+// Best possible score for a known sequence of drawn tiles.
+//
+// With perfect foresight every tile can be put on any field that is still
+// empty when it is drawn, so the order of the draw does not restrict the
+// result: the best score is the best assignment of the tiles to the fields.
+
+pub struct Oracle {}
+
+impl Oracle {
+    // tiles of the classic game
+    pub fn best_board(tiles: &[Tile]) -> Result<(Board, u32), String> {
+        Oracle::best_board_with(tiles, &RuleSet::default())
+    }
+
+    pub fn best_board_with(tiles: &[Tile], rules: &RuleSet) -> Result<(Board, u32), String> {
+        let num_fields = rules.num_fields();
+        if tiles.len() != num_fields {
+            return Err(format!(
+                "expected {} tiles, got {}",
+                num_fields,
+                tiles.len()
+            ));
+        }
+        if tiles.iter().collect::<HashSet<&Tile>>().len() != tiles.len() {
+            return Err("tiles must be distinct".to_string());
+        }
+        if let Some(tile) = tiles.iter().find(|tile| !rules.contains(tile)) {
+            return Err(format!("{} is not a tile of the rule set", tile));
+        }
+
+        let mut search = Search::new(tiles, rules);
+        search.best(0, 0);
+
+        search.targets = search.best_targets.clone();
+        let board = search.board(&search.matching().unwrap());
+        let score = board.score();
+        Ok((board, score))
+    }
+}
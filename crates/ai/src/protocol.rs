@@ -0,0 +1,259 @@
+use crate::ai::AI;
+use std::cell::Cell;
+use std::io::{self, BufRead, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+use takeiteasy_core::record::{format_field, format_tile, parse_field_with, parse_tile_with};
+use takeiteasy_core::rules::RuleSet;
+use takeiteasy_core::tile::Tile;
+use takeiteasy_core::{Board, Field};
+
+// Line based protocol to drive the AI from another program, like UCI for
+// chess engines. Tiles and fields are written as in game records (`973`,
+// `C3R2`).
+//
+// commands:
+//     isready                    answered with `readyok` once a running search is done
+//     newgame                    empty board, no tile
+//     rules <rule set>           new game with these rules, e.g. `rules 159 267 348 r1`
+//                                or `rules classic`
+//     setboard [<tile> <field>]* board with these tiles, e.g. `setboard 973 C1R1 528 C3R3`
+//     tile <tile>                the tile to place next
+//     place <field>              place the tile on the board
+//     go depth <n>               search up to depth n
+//     go movetime <ms>           search for about ms milliseconds
+//     stop                       end the search
+//     quit                       end the search and the engine
+//
+// responses:
+//     info depth <n> field <field> ev <score> time <ms>   after every finished depth
+//     info depth <n> fields <done>/<all> time <ms>        every second within a depth
+//     bestmove <field> ev <score>                          at the end of a search
+//     error <message>                                      for invalid commands
+//
+// While a search runs only `stop` and `quit` are handled right away, other
+// commands wait until the search is done. At the end of the input a running
+// search is finished. Without a `rules` command the classic rules are used.
+
+const INFO_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy)]
+enum Limit {
+    Depth(i32),
+    MoveTime(Duration),
+}
+
+fn parse_board(words: &[&str], rules: &RuleSet) -> Result<Board, String> {
+    if !words.len().is_multiple_of(2) {
+        return Err("setboard needs pairs of tile and field".to_string());
+    }
+    let mut board = Board::with_rules(*rules);
+    for pair in words.chunks(2) {
+        let tile = parse_tile_with(pair[0], rules)?;
+        let field = parse_field_with(pair[1], rules)?;
+        board
+            .place_tile(field, tile)
+            .map_err(|_| format!("cannot place {} at {}", pair[0], pair[1]))?;
+    }
+    Ok(board)
+}
+
+fn parse_limit(words: &[&str]) -> Result<Limit, String> {
+    match words {
+        ["depth", n] => n
+            .parse::<i32>()
+            .ok()
+            .filter(|&n| n >= 0)
+            .map(Limit::Depth)
+            .ok_or(format!("invalid depth: {}", n)),
+        ["movetime", ms] => ms
+            .parse::<u64>()
+            .map(|ms| Limit::MoveTime(Duration::from_millis(ms)))
+            .map_err(|_| format!("invalid movetime: {}", ms)),
+        _ => Err("go needs `depth <n>` or `movetime <ms>`".to_string()),
+    }
+}
+
+fn send<W: Write>(output: &Mutex<W>, line: &str) {
+    let mut output = output.lock().unwrap();
+    let _ = writeln!(output, "{}", line);
+    let _ = output.flush();
+}
+
+// iterative deepening over the empty fields, a stopped depth is dropped and
+// the best field of the last finished one is taken
+fn search<W: Write>(board: &Board, tile: Tile, limit: Limit, stop: &AtomicBool, output: &Mutex<W>) {
+    let start = Instant::now();
+    let (max_depth, deadline) = match limit {
+        Limit::Depth(depth) => (depth, None),
+        Limit::MoveTime(time) => (board.empty_fields().len() as i32, Some(start + time)),
+    };
+    let mut fields: Vec<Field> = board.empty_fields().into_iter().collect();
+    fields.sort();
+
+    // depth and number of fields done, for the progress lines
+    let progress = Cell::new((0, 0));
+    let last_info = Cell::new(start);
+    let stopped = || {
+        if last_info.get().elapsed() >= INFO_INTERVAL {
+            last_info.set(Instant::now());
+            let (depth, done) = progress.get();
+            send(
+                output,
+                &format!(
+                    "info depth {} fields {}/{} time {}",
+                    depth,
+                    done,
+                    fields.len(),
+                    start.elapsed().as_millis()
+                ),
+            );
+        }
+        stop.load(Ordering::Relaxed) || deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    };
+
+    let mut best: Option<(Field, f64)> = None;
+    'depths: for depth in 0..=max_depth {
+        let mut depth_best: Option<(Field, f64)> = None;
+        for (done, &field) in fields.iter().enumerate() {
+            progress.set((depth, done));
+            let new_board = board.place_tile_on_new_board(field, tile).unwrap();
+            // the first depth is cheap and always finished
+            let ev = match AI::estimated_score_until(&new_board, depth, &stopped) {
+                Some(ev) => ev,
+                None => break 'depths,
+            };
+            if depth_best.is_none_or(|(_, best_ev)| ev > best_ev) {
+                depth_best = Some((field, ev));
+            }
+        }
+        best = depth_best;
+        let (field, ev) = best.unwrap();
+        send(
+            output,
+            &format!(
+                "info depth {} field {} ev {:.1} time {}",
+                depth,
+                format_field(&field),
+                ev,
+                start.elapsed().as_millis()
+            ),
+        );
+        if stopped() {
+            break;
+        }
+    }
+    let (field, ev) = best.unwrap();
+    send(
+        output,
+        &format!("bestmove {} ev {:.1}", format_field(&field), ev),
+    );
+}
+
+pub fn run<R: BufRead, W: Write + Send>(input: R, output: W) -> io::Result<()> {
+    run_with(input, output, RuleSet::default())
+}
+
+// like `run`, with these rules until a `rules` command
+pub fn run_with<R: BufRead, W: Write + Send>(
+    input: R,
+    output: W,
+    mut rules: RuleSet,
+) -> io::Result<()> {
+    let output = Mutex::new(output);
+    let stop = AtomicBool::new(false);
+    let mut board = Board::with_rules(rules);
+    let mut tile: Option<Tile> = None;
+
+    thread::scope(|scope| {
+        let mut running: Option<thread::ScopedJoinHandle<()>> = None;
+        let finish = |running: &mut Option<thread::ScopedJoinHandle<()>>, stop_now: bool| {
+            if let Some(handle) = running.take() {
+                stop.store(stop_now, Ordering::Relaxed);
+                let _ = handle.join();
+            }
+            stop.store(false, Ordering::Relaxed);
+        };
+
+        for line in input.lines() {
+            let line = line?;
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let Some((&command, arguments)) = words.split_first() else {
+                continue;
+            };
+            match command {
+                "stop" => {
+                    finish(&mut running, true);
+                    continue;
+                }
+                "quit" => {
+                    finish(&mut running, true);
+                    return Ok(());
+                }
+                _ => finish(&mut running, false),
+            }
+
+            let result = match command {
+                "isready" => {
+                    send(&output, "readyok");
+                    Ok(())
+                }
+                "newgame" => {
+                    board = Board::with_rules(rules);
+                    tile = None;
+                    Ok(())
+                }
+                "rules" => arguments.join(" ").parse().map(|new_rules| {
+                    rules = new_rules;
+                    board = Board::with_rules(rules);
+                    tile = None;
+                }),
+                "setboard" => parse_board(arguments, &rules).map(|new_board| {
+                    board = new_board;
+                    tile = None;
+                }),
+                "tile" => match arguments {
+                    [text] => parse_tile_with(text, &rules).and_then(|new_tile| {
+                        if board.tiles.values().any(|t| *t == Some(new_tile)) {
+                            return Err(format!("{} is already on the board", text));
+                        }
+                        tile = Some(new_tile);
+                        Ok(())
+                    }),
+                    _ => Err("tile needs one tile".to_string()),
+                },
+                "place" => match (arguments, tile) {
+                    ([text], Some(current)) => parse_field_with(text, &rules).and_then(|field| {
+                        board.place_tile(field, current).map_err(|_| {
+                            format!("cannot place {} at {}", format_tile(&current), text)
+                        })?;
+                        tile = None;
+                        Ok(())
+                    }),
+                    (_, None) => Err("no tile".to_string()),
+                    _ => Err("place needs one field".to_string()),
+                },
+                "go" => match (parse_limit(arguments), tile) {
+                    (Err(e), _) => Err(e),
+                    (_, None) => Err("no tile".to_string()),
+                    _ if board.is_full() => Err("the board is full".to_string()),
+                    (Ok(limit), Some(tile)) => {
+                        let board = board.clone();
+                        let (stop, output) = (&stop, &output);
+                        running =
+                            Some(scope.spawn(move || search(&board, tile, limit, stop, output)));
+                        Ok(())
+                    }
+                },
+                _ => Err(format!("unknown command: {}", command)),
+            };
+            if let Err(e) = result {
+                send(&output, &format!("error {}", e));
+            }
+        }
+        finish(&mut running, false);
+        Ok(())
+    })
+}
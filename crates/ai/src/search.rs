@@ -0,0 +1,336 @@
+use std::collections::HashSet;
+use strum::IntoEnumIterator;
+use takeiteasy_core::rules::RuleSet;
+use takeiteasy_core::tile::{Direction, Tile};
+use takeiteasy_core::{Board, Field};
+
+// Search over line targets shared by the oracle and the solver.
+//
+// Instead of trying tile permutations the search decides for every line which
+// number it should score (or none) and checks with a bipartite matching
+// whether the tiles can still fill the fields under those targets. Branches
+// are cut with the best score the undecided lines could add.
+
+pub(crate) struct Line {
+    direction: Direction,
+    fields: Vec<usize>,
+}
+
+pub(crate) struct Search {
+    rules: RuleSet,
+    pub(crate) tiles: Vec<Tile>,
+    pub(crate) fields: Vec<Field>,
+    lines: Vec<Line>,
+    // line index per direction for every field
+    field_lines: Vec<Vec<usize>>,
+    // None: undecided, Some(None): line does not need to score, Some(Some(n)): line scores n
+    pub(crate) targets: Vec<Option<Option<u32>>>,
+    // numbers the tiles offer per line, highest first
+    options: Vec<Vec<u32>>,
+    pub(crate) best_score: u32,
+    pub(crate) best_targets: Vec<Option<Option<u32>>>,
+}
+
+impl Search {
+    pub(crate) fn new(tiles: &[Tile], rules: &RuleSet) -> Search {
+        let fields: Vec<Field> = rules.fields().into_iter().collect();
+
+        let mut lines = Vec::new();
+        for direction in Direction::iter() {
+            for section in rules.lines(&direction) {
+                let fields = section
+                    .iter()
+                    .map(|f| fields.iter().position(|field| field == f).unwrap())
+                    .collect();
+                lines.push(Line { direction, fields });
+            }
+        }
+        // long lines first, they decide the most points
+        lines.sort_by_key(|line| std::cmp::Reverse(line.fields.len()));
+
+        let mut field_lines = vec![Vec::new(); fields.len()];
+        for (i, line) in lines.iter().enumerate() {
+            for &field in &line.fields {
+                field_lines[field].push(i);
+            }
+        }
+
+        let options = lines
+            .iter()
+            .map(|line| {
+                let mut numbers: Vec<u32> = tiles
+                    .iter()
+                    .map(|t| t.number(&line.direction))
+                    .collect::<HashSet<u32>>()
+                    .into_iter()
+                    .filter(|&n| {
+                        tiles
+                            .iter()
+                            .filter(|t| t.number(&line.direction) == n)
+                            .count()
+                            >= line.fields.len()
+                    })
+                    .collect();
+                numbers.sort_by(|a, b| b.cmp(a));
+                numbers
+            })
+            .collect();
+
+        let num_lines = lines.len();
+        Search {
+            rules: *rules,
+            tiles: tiles.to_vec(),
+            fields,
+            lines,
+            field_lines,
+            targets: vec![None; num_lines],
+            options,
+            best_score: 0,
+            best_targets: vec![Some(None); num_lines],
+        }
+    }
+
+    // bit mask of the tiles that fit on a field under the current targets
+    fn fitting_tiles(&self, field: usize) -> u128 {
+        let mut mask = u128::MAX >> (128 - self.tiles.len());
+        for &line in &self.field_lines[field] {
+            if let Some(Some(n)) = self.targets[line] {
+                let direction = &self.lines[line].direction;
+                for (i, tile) in self.tiles.iter().enumerate() {
+                    if tile.number(direction) != n {
+                        mask &= !(1 << i);
+                    }
+                }
+            }
+        }
+        mask
+    }
+
+    fn augment(
+        field: usize,
+        fitting: &[u128],
+        seen: &mut u128,
+        tile_field: &mut [Option<usize>],
+    ) -> bool {
+        let mut candidates = fitting[field] & !*seen;
+        while candidates != 0 {
+            let tile = candidates.trailing_zeros() as usize;
+            candidates &= candidates - 1;
+            *seen |= 1 << tile;
+            if tile_field[tile].is_none()
+                || Search::augment(tile_field[tile].unwrap(), fitting, seen, tile_field)
+            {
+                tile_field[tile] = Some(field);
+                return true;
+            }
+        }
+        false
+    }
+
+    // tile index per field, if every field can get a different tile of its mask
+    fn match_fields(fitting: &[u128], num_tiles: usize) -> Option<Vec<usize>> {
+        let mut tile_field = vec![None; num_tiles];
+        for field in 0..fitting.len() {
+            let mut seen = 0;
+            if !Search::augment(field, fitting, &mut seen, &mut tile_field) {
+                return None;
+            }
+        }
+        let mut field_tile = vec![0; fitting.len()];
+        for (tile, field) in tile_field.iter().enumerate() {
+            if let Some(field) = field {
+                field_tile[*field] = tile;
+            }
+        }
+        Some(field_tile)
+    }
+
+    // tile index per field, if every field can get a tile matching the targets
+    pub(crate) fn matching(&self) -> Option<Vec<usize>> {
+        let fitting: Vec<u128> = (0..self.fields.len())
+            .map(|field| self.fitting_tiles(field))
+            .collect();
+        Search::match_fields(&fitting, self.tiles.len())
+    }
+
+    // best score for lines of the given lengths if each number only has
+    // `capacity` tiles left
+    fn capacity_bound(lengths: &[usize], capacity: &mut Vec<(u32, usize)>) -> u32 {
+        let Some((&len, rest)) = lengths.split_first() else {
+            return 0;
+        };
+        let mut best = Search::capacity_bound(rest, capacity);
+        for i in 0..capacity.len() {
+            let (number, left) = capacity[i];
+            if left >= len {
+                capacity[i].1 -= len;
+                best = best.max(number * len as u32 + Search::capacity_bound(rest, capacity));
+                capacity[i].1 += len;
+            }
+        }
+        best
+    }
+
+    // best score the undecided lines could still add, None if the decided
+    // lines already need more tiles of a number than there are
+    fn bound(&self, line: usize) -> Option<u32> {
+        let mut bound = 0;
+        for direction in Direction::iter() {
+            let mut capacity: Vec<(u32, usize)> = Vec::new();
+            for tile in &self.tiles {
+                let number = tile.number(&direction);
+                match capacity.iter_mut().find(|(n, _)| *n == number) {
+                    Some(entry) => entry.1 += 1,
+                    None => capacity.push((number, 1)),
+                }
+            }
+            for l in 0..line {
+                if self.lines[l].direction != direction {
+                    continue;
+                }
+                if let Some(Some(number)) = self.targets[l] {
+                    let entry = capacity.iter_mut().find(|(n, _)| *n == number).unwrap();
+                    entry.1 = entry.1.checked_sub(self.lines[l].fields.len())?;
+                }
+            }
+            let lengths: Vec<usize> = (line..self.lines.len())
+                .filter(|&l| self.lines[l].direction == direction)
+                .map(|l| self.lines[l].fields.len())
+                .collect();
+            bound += Search::capacity_bound(&lengths, &mut capacity);
+        }
+        Some(bound)
+    }
+
+    pub(crate) fn best(&mut self, line: usize, score: u32) {
+        match self.bound(line) {
+            Some(bound) if score + bound > self.best_score => {}
+            _ => return,
+        }
+        if self.matching().is_none() {
+            return;
+        }
+        if line == self.lines.len() {
+            self.best_score = score;
+            self.best_targets = self.targets.clone();
+            return;
+        }
+
+        let len = self.lines[line].fields.len() as u32;
+        for option in self.options[line].clone() {
+            self.targets[line] = Some(Some(option));
+            self.best(line + 1, score + option * len);
+        }
+        self.targets[line] = Some(None);
+        self.best(line + 1, score);
+        self.targets[line] = None;
+    }
+
+    // visits every feasible target set scoring exactly `goal`, stops as soon
+    // as `visit` returns true
+    pub(crate) fn exact(
+        &mut self,
+        line: usize,
+        score: u32,
+        goal: u32,
+        visit: &mut dyn FnMut(&Search) -> bool,
+    ) -> bool {
+        if score > goal {
+            return false;
+        }
+        match self.bound(line) {
+            Some(bound) if score + bound >= goal => {}
+            _ => return false,
+        }
+        if self.matching().is_none() {
+            return false;
+        }
+        if line == self.lines.len() {
+            return score == goal && visit(self);
+        }
+
+        let len = self.lines[line].fields.len() as u32;
+        let mut stop = false;
+        for option in self.options[line].clone() {
+            self.targets[line] = Some(Some(option));
+            stop = self.exact(line + 1, score + option * len, goal, visit);
+            if stop {
+                break;
+            }
+        }
+        if !stop {
+            self.targets[line] = Some(None);
+            stop = self.exact(line + 1, score, goal, visit);
+        }
+        self.targets[line] = None;
+        stop
+    }
+
+    fn assign(
+        &self,
+        field: usize,
+        fitting: &[u128],
+        used: u128,
+        assignment: &mut Vec<usize>,
+        visit: &mut dyn FnMut(&[usize]) -> bool,
+    ) -> bool {
+        if field == fitting.len() {
+            return visit(assignment);
+        }
+        let unused: Vec<u128> = fitting[field..].iter().map(|mask| mask & !used).collect();
+        if Search::match_fields(&unused, self.tiles.len()).is_none() {
+            return false;
+        }
+        let mut candidates = fitting[field] & !used;
+        while candidates != 0 {
+            let tile = candidates.trailing_zeros() as usize;
+            candidates &= candidates - 1;
+            assignment.push(tile);
+            if self.completes_untargeted_line(field, assignment) {
+                assignment.pop();
+                continue;
+            }
+            let stop = self.assign(field + 1, fitting, used | 1 << tile, assignment, visit);
+            assignment.pop();
+            if stop {
+                return true;
+            }
+        }
+        false
+    }
+
+    // whether placing the last tile completes a line without a target that
+    // would score anyway
+    fn completes_untargeted_line(&self, field: usize, assignment: &[usize]) -> bool {
+        self.field_lines[field].iter().any(|&line| {
+            let line_fields = &self.lines[line].fields;
+            if matches!(self.targets[line], Some(Some(_))) || line_fields.iter().any(|&f| f > field)
+            {
+                return false;
+            }
+            let direction = &self.lines[line].direction;
+            let number = self.tiles[assignment[field]].number(direction);
+            line_fields
+                .iter()
+                .all(|&f| self.tiles[assignment[f]].number(direction) == number)
+        })
+    }
+
+    // visits every tile index per field that matches the current targets and
+    // scores no other line,
+    // stops as soon as `visit` returns true
+    pub(crate) fn assignments(&self, visit: &mut dyn FnMut(&[usize]) -> bool) -> bool {
+        let fitting: Vec<u128> = (0..self.fields.len())
+            .map(|field| self.fitting_tiles(field))
+            .collect();
+        self.assign(0, &fitting, 0, &mut Vec::new(), visit)
+    }
+
+    pub(crate) fn board(&self, assignment: &[usize]) -> Board {
+        let mut board = Board::with_rules(self.rules);
+        for (field, &tile) in self.fields.iter().zip(assignment) {
+            board.place_tile(*field, self.tiles[tile]).unwrap();
+        }
+        board
+    }
+}
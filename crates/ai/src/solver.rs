@@ -0,0 +1,112 @@
+use crate::search::Search;
+use std::collections::HashSet;
+use takeiteasy_core::rules::RuleSet;
+use takeiteasy_core::tile::Tile;
+use takeiteasy_core::{Board, Field};
+
+// Highest possible score over any tiles of a full board, of the classic game
+// unless a rule set is given.
+//
+// Turning the whole board by 180° keeps every line in its direction, so each
+// board has a rotated twin with the same score. Boards are only reported
+// once per pair.
+
+fn rotated(field: &Field, rules: &RuleSet) -> Field {
+    rules.hex(field).rotate(3).to_field(rules.radius()).unwrap()
+}
+
+// (column, row, top, left, right) sorted by field, the smaller one of the
+// board and its rotated twin
+fn canonical_key(board: &Board) -> Vec<(i32, i32, u32, u32, u32)> {
+    let key = |rotate: bool| {
+        let mut key: Vec<(i32, i32, u32, u32, u32)> = board
+            .tiles
+            .iter()
+            .map(|(field, tile)| {
+                let field = if rotate {
+                    rotated(field, board.rules())
+                } else {
+                    *field
+                };
+                let tile = tile.unwrap();
+                (
+                    field.column as i32,
+                    field.row as i32,
+                    tile.top,
+                    tile.left,
+                    tile.right,
+                )
+            })
+            .collect();
+        key.sort();
+        key
+    };
+    key(false).min(key(true))
+}
+
+fn search(rules: &RuleSet) -> Search {
+    let tiles: Vec<Tile> = rules.tiles().into_iter().collect();
+    Search::new(&tiles, rules)
+}
+
+pub struct Solver {}
+
+impl Solver {
+    pub fn max_score() -> u32 {
+        Solver::max_score_with(&RuleSet::default())
+    }
+
+    pub fn max_score_with(rules: &RuleSet) -> u32 {
+        let mut search = search(rules);
+        search.best(0, 0);
+        search.best_score
+    }
+
+    // every distinct board reaching the maximum score, modulo the 180° rotation
+    pub fn best_boards() -> Vec<Board> {
+        Solver::best_boards_with(&RuleSet::default())
+    }
+
+    pub fn best_boards_with(rules: &RuleSet) -> Vec<Board> {
+        let max_score = Solver::max_score_with(rules);
+        let mut keys = HashSet::new();
+        let mut boards = Vec::new();
+        let mut search = search(rules);
+        search.exact(0, 0, max_score, &mut |search| {
+            search.assignments(&mut |assignment| {
+                let board = search.board(assignment);
+                if keys.insert(canonical_key(&board)) {
+                    boards.push(board);
+                }
+                false
+            });
+            false
+        });
+        boards
+    }
+
+    // some board with exactly the given score
+    pub fn board_with_score(score: u32) -> Option<Board> {
+        Solver::board_with_score_with(score, &RuleSet::default())
+    }
+
+    pub fn board_with_score_with(score: u32, rules: &RuleSet) -> Option<Board> {
+        let mut found = None;
+        let mut search = search(rules);
+        search.exact(0, 0, score, &mut |search| {
+            search.assignments(&mut |assignment| {
+                found = Some(search.board(assignment));
+                true
+            })
+        });
+        found
+    }
+
+    pub fn can_reach(score: u32) -> bool {
+        Solver::can_reach_with(score, &RuleSet::default())
+    }
+
+    pub fn can_reach_with(score: u32, rules: &RuleSet) -> bool {
+        Solver::board_with_score_with(score, rules).is_some()
+    }
+}
@@ -0,0 +1,5 @@
+// Links the `takeiteasy` crate into a shared library for C, Python (maturin)
+// and JavaScript (wasm-pack). The exported functions are the ones of its
+// `ffi`, `python` and `wasm` modules.
+
+pub use facade::*;
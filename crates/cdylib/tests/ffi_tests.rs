@@ -0,0 +1,76 @@
+#![cfg(feature = "ffi")]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// the bindings, the header and the C program are in the `takeiteasy` crate
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+// target/debug/deps, the cdylib of this build is next to the test binary
+fn library_dir() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    exe.parent().unwrap().to_path_buf()
+}
+
+#[test]
+fn test_header_is_up_to_date() {
+    let config = cbindgen::Config::from_file(root().join("cbindgen.toml")).unwrap();
+    let mut header = Vec::new();
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(root().join("src/ffi.rs"))
+        .generate()
+        .unwrap()
+        .write(&mut header);
+    let path = root().join("include/takeiteasy.h");
+    if env::var_os("UPDATE_HEADER").is_some() {
+        fs::write(&path, &header).unwrap();
+    }
+    assert!(
+        fs::read(&path).unwrap() == header,
+        "include/takeiteasy.h is outdated, regenerate it with UPDATE_HEADER=1"
+    );
+}
+
+// cargo does not build a cdylib for the tests of its own crate
+fn build_library() {
+    let mut cargo = Command::new(env!("CARGO"));
+    cargo.args(["build", "-p", "takeiteasy-cdylib"]);
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    assert!(cargo.status().unwrap().success());
+}
+
+#[test]
+fn test_c_program() {
+    build_library();
+    let out = env::temp_dir().join(format!("takeiteasy_ffi_test_{}", std::process::id()));
+    let lib = library_dir();
+    let status = Command::new(env::var("CC").unwrap_or("cc".to_string()))
+        .arg(root().join("tests/c/ffi_test.c"))
+        .arg("-I")
+        .arg(root().join("include"))
+        .arg("-L")
+        .arg(&lib)
+        .arg("-ltakeiteasy")
+        .arg("-o")
+        .arg(&out)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    // cargo points the library path to other builds of the library
+    let output = Command::new(&out)
+        .env("LD_LIBRARY_PATH", &lib)
+        .output()
+        .unwrap();
+    let _ = fs::remove_file(&out);
+    assert_eq!("", String::from_utf8_lossy(&output.stderr));
+    assert!(output.status.success());
+    assert_eq!("ok\n", String::from_utf8_lossy(&output.stdout));
+}
@@ -0,0 +1,171 @@
+use std::path::Path;
+use takeiteasy::ai::AI;
+use takeiteasy::record::{format_field, format_tile, GameRecord};
+use takeiteasy::rules::RuleSet;
+use takeiteasy::*;
+
+// The subcommands of the binary besides `play`.
+
+fn read_file(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn read_record(path: &Path) -> Result<GameRecord, String> {
+    GameRecord::parse(&read_file(path)?).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn board_text(board: &Board, colored: bool) -> String {
+    board
+        .view(render::Style::Large)
+        .colored(colored)
+        .to_string()
+}
+
+pub fn ai(game: Game, depth: i32, threads: usize, colored: bool) {
+    let mut game = game;
+    while !game.finished() {
+        let tile = game.current_tile.unwrap();
+        let field = AI::best_field_threaded(&game.board, tile, depth, threads).unwrap();
+        game.place_tile(field).unwrap();
+    }
+    let mut record = GameRecord::from_game(&game);
+    record
+        .set_tag("Players", &format!("AI depth {}", depth))
+        .unwrap();
+    println!("{}", board_text(&game.board, colored));
+    println!();
+    print!("{}", record);
+}
+
+fn play_ai_game(seed: u64, depth: i32, rules: RuleSet) -> u32 {
+    let mut game = Game::with_rules(rules, seed);
+    while !game.finished() {
+        let tile = game.current_tile.unwrap();
+        let field = AI::best_field(&game.board, tile, depth).unwrap();
+        game.place_tile(field).unwrap();
+    }
+    game.board.score()
+}
+
+pub fn simulate(games: u32, depth: i32, threads: usize, seed: u64, rules: RuleSet) {
+    let threads = threads.max(1) as u64;
+    let mut scores: Vec<u32> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|thread| {
+                scope.spawn(move || {
+                    (0..games as u64)
+                        .filter(|game| game % threads == thread)
+                        .map(|game| play_ai_game(seed.wrapping_add(game), depth, rules))
+                        .collect::<Vec<u32>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+    if scores.is_empty() {
+        println!("no games");
+        return;
+    }
+    scores.sort();
+
+    let n = scores.len() as f64;
+    let mean = scores.iter().sum::<u32>() as f64 / n;
+    let variance = scores
+        .iter()
+        .map(|&s| (s as f64 - mean).powi(2))
+        .sum::<f64>()
+        / n;
+    println!("games:     {}", scores.len());
+    println!("seeds:     {}..{}", seed, seed.wrapping_add(games as u64));
+    println!("mean:      {:.1}", mean);
+    println!("std dev:   {:.1}", variance.sqrt());
+    println!("median:    {}", scores[scores.len() / 2]);
+    println!("min:       {}", scores[0]);
+    println!("max:       {}", scores[scores.len() - 1]);
+}
+
+pub fn analyze(path: &Path, depth: i32) -> Result<(), String> {
+    let record = read_record(path)?;
+    let mut board = Board::with_rules(record.rules()?);
+    let mut total_loss = 0.0;
+    for (i, m) in record.moves.iter().enumerate() {
+        let played = board
+            .place_tile_on_new_board(m.field, m.tile)
+            .map_err(|_| {
+                format!(
+                    "move {}: cannot place {} at {}",
+                    i + 1,
+                    format_tile(&m.tile),
+                    format_field(&m.field)
+                )
+            })?;
+        let best = AI::best_field(&board, m.tile, depth).unwrap();
+        let text = format!(
+            "{:>2}. {} {}",
+            i + 1,
+            format_tile(&m.tile),
+            format_field(&m.field)
+        );
+        if best == m.field {
+            println!("{}   best", text);
+        } else {
+            let best_board = board.place_tile_on_new_board(best, m.tile).unwrap();
+            let loss = AI::estimated_score(&best_board, depth).unwrap()
+                - AI::estimated_score(&played, depth).unwrap();
+            total_loss += loss.max(0.0);
+            println!("{}   AI: {} ({:+.1})", text, format_field(&best), -loss);
+        }
+        board = played;
+    }
+    println!();
+    println!("score: {}", board.score());
+    println!("estimated loss against the AI: {:.1}", total_loss);
+    Ok(())
+}
+
+pub fn replay(path: &Path, colored: bool) -> Result<(), String> {
+    let record = read_record(path)?;
+    for (name, value) in &record.tags {
+        println!("{}: {}", name, value);
+    }
+    if let Some(comment) = &record.comment {
+        println!("{}", comment);
+    }
+    let mut board = Board::with_rules(record.rules()?);
+    for (i, m) in record.moves.iter().enumerate() {
+        board.place_tile(m.field, m.tile).map_err(|_| {
+            format!(
+                "move {}: cannot place {} at {}",
+                i + 1,
+                format_tile(&m.tile),
+                format_field(&m.field)
+            )
+        })?;
+        println!();
+        println!(
+            "move {}: {} {}",
+            i + 1,
+            format_tile(&m.tile),
+            format_field(&m.field)
+        );
+        if let Some(comment) = &m.comment {
+            println!("{}", comment);
+        }
+        println!("{}", board_text(&board, colored));
+    }
+    println!();
+    println!("score: {}", board.score());
+    Ok(())
+}
+
+pub fn score(path: &Path, rules: &RuleSet, colored: bool) -> Result<(), String> {
+    let board = Board::from_ascii_with(&read_file(path)?, rules)
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    println!("{}", board_text(&board, colored));
+    println!();
+    println!("score: {}", board.score());
+    Ok(())
+}
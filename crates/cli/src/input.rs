@@ -0,0 +1,68 @@
+use takeiteasy::rules::RuleSet;
+use takeiteasy::Field;
+
+// What the player can type at the prompt of the console game.
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Input {
+    Place(Field),
+    Undo,
+    Hint,
+    Board,
+    Tiles,
+    Opponents,
+    Help,
+    Save(String),
+    Load(String),
+    Quit,
+}
+
+pub const HELP: &str = "\
+place the tile by typing its field in one of these ways:
+  3 2       column and row
+  3,2       column and row
+  c3r2      column and row
+  i         the letter shown in the empty field, on small boards
+  center    center, top, bottom, top-left, top-right, bottom-left, bottom-right
+commands:
+  undo      take back the last move
+  hint      show where the AI would place the tile
+  board     print the board again
+  tiles     list the tiles that are still in the bag
+  opponents show the boards of the AI opponents
+  help      show this text
+  save FILE save the game, continue it with load or play --resume
+  load FILE continue a saved game
+  quit      end the game";
+
+// single letters are fields, so the commands have no one letter shortcuts;
+// fields are those of the board of the rule set
+pub fn parse_input(text: &str, rules: &RuleSet) -> Result<Input, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("please type a field or a command".to_string());
+    }
+    if let Some((command, file)) = text.split_once(char::is_whitespace) {
+        let file = file.trim().to_string();
+        match command.to_ascii_lowercase().as_str() {
+            "save" => return Ok(Input::Save(file)),
+            "load" => return Ok(Input::Load(file)),
+            _ => {}
+        }
+    }
+    let command = match text.to_ascii_lowercase().as_str() {
+        "undo" => Some(Input::Undo),
+        "hint" => Some(Input::Hint),
+        "board" => Some(Input::Board),
+        "tiles" => Some(Input::Tiles),
+        "opponents" => Some(Input::Opponents),
+        "help" | "?" => Some(Input::Help),
+        "save" | "load" => return Err(format!("{} needs a file name", text)),
+        "quit" | "exit" => Some(Input::Quit),
+        _ => None,
+    };
+    match command {
+        Some(command) => Ok(command),
+        None => Field::parse_with(text, rules).map(Input::Place),
+    }
+}
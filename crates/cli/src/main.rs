@@ -0,0 +1,375 @@
+mod commands;
+mod input;
+mod opponents;
+#[cfg(feature = "tui")]
+mod tui;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use input::Input;
+use opponents::Opponent;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use takeiteasy::ai::AI;
+use takeiteasy::rules::RuleSet;
+use takeiteasy::tile::Tile;
+use takeiteasy::*;
+
+#[derive(Parser)]
+#[command(version, about = "The board game Take It Easy")]
+struct Cli {
+    /// Color the numbers like the bars of the physical tiles
+    #[arg(long, value_enum, global = true, default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
+
+    /// Numbers and board of a game variant, a TOML or JSON file with top, left, right
+    /// and optionally radius
+    #[arg(long, value_name = "FILE", global = true)]
+    rules: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+// a negative depth would never end the search
+fn depth_parser() -> clap::builder::RangedI64ValueParser<i32> {
+    clap::value_parser!(i32).range(0..)
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ColorChoice {
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Auto => color::color_enabled(),
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Play a game on the console (the default)
+    Play {
+        /// Seed of the tile order, random if not given
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Show where the AI would place each tile
+        #[arg(long)]
+        hints: bool,
+        /// Full-screen terminal UI
+        #[arg(long, conflicts_with = "opponents")]
+        tui: bool,
+        /// Play against an AI of this search depth, can be repeated
+        #[arg(long = "opponent", value_name = "DEPTH", value_parser = depth_parser())]
+        opponents: Vec<i32>,
+        /// Continue a game saved with the save command
+        #[arg(long, value_name = "FILE", conflicts_with = "seed")]
+        resume: Option<PathBuf>,
+    },
+    /// Let the AI play a game and print it
+    Ai {
+        #[arg(long, default_value_t = 1, value_parser = depth_parser())]
+        depth: i32,
+        #[arg(long, default_value_t = 1)]
+        threads: usize,
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Let the AI play many games and print statistics of the scores
+    Simulate {
+        #[arg(long, default_value_t = 100)]
+        games: u32,
+        #[arg(long, default_value_t = 0, value_parser = depth_parser())]
+        depth: i32,
+        #[arg(long, default_value_t = 1)]
+        threads: usize,
+        /// Seed of the first game, the following games count up
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Review a game record move by move against the AI
+    Analyze {
+        record: PathBuf,
+        #[arg(long, default_value_t = 1, value_parser = depth_parser())]
+        depth: i32,
+    },
+    /// Print the board after every move of a game record
+    Replay { record: PathBuf },
+    /// Print the score of a board drawing
+    Score { board_file: PathBuf },
+    /// Let another program drive the AI over stdin and stdout
+    Engine,
+    /// Serve games over HTTP with a JSON API and multiplayer rooms over WebSocket
+    #[cfg(feature = "server")]
+    Serve {
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        address: String,
+        /// Address of the WebSocket rooms
+        #[arg(long, default_value = "127.0.0.1:8081")]
+        rooms: String,
+    },
+}
+
+// None at the end of the input
+fn read_console_input(msg: &str) -> Option<String> {
+    // prompt
+    print! {"{}", msg};
+    io::stdout().flush().unwrap();
+
+    // read
+    let mut input = String::new();
+    match io::stdin().read_line(&mut input) {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(input.trim().to_string()),
+    }
+}
+
+fn print_board(board: &Board, colored: bool) {
+    println!(
+        "{}",
+        board
+            .view(render::Style::Large)
+            .colored(colored)
+            .labels(true)
+    );
+}
+
+fn tile_text(tile: &Tile, colored: bool) -> String {
+    if colored {
+        tile.colored()
+    } else {
+        tile.to_string()
+    }
+}
+
+// with the id if the board has ids
+fn field_text(field: &Field, rules: &RuleSet) -> String {
+    match field.id_with(rules) {
+        Some(id) => format!("{} ({})", field, id),
+        None => field.to_string(),
+    }
+}
+
+fn print_hint(game: &Game) {
+    let tile = game.current_tile.unwrap();
+    if let Ok(field) = AI::best_field(&game.board, tile, 1) {
+        println!("hint: {}", field_text(&field, game.rules()));
+    }
+}
+
+fn print_tiles(game: &Game, colored: bool) {
+    let mut tiles: Vec<Tile> = game
+        .board
+        .remaining_tiles()
+        .into_iter()
+        .filter(|tile| Some(*tile) != game.current_tile)
+        .collect();
+    tiles.sort();
+    println!("{} tiles left:", tiles.len());
+    for chunk in tiles.chunks(6) {
+        let chunk: Vec<String> = chunk.iter().map(|t| tile_text(t, colored)).collect();
+        println!("  {}", chunk.join(" "));
+    }
+}
+
+fn catch_up(opponents: &mut [Opponent], game: &Game) {
+    for opponent in opponents.iter_mut() {
+        opponent.catch_up(game.moves());
+    }
+}
+
+fn play(game: Game, colored: bool, hints: bool, opponents: Vec<Opponent>) {
+    let mut game = game;
+    let mut opponents = opponents;
+    // games and opponents before each move, for undo
+    let mut history: Vec<(Game, Vec<Opponent>)> = Vec::new();
+
+    catch_up(&mut opponents, &game);
+    print_board(&game.board, colored);
+    while !game.finished() {
+        let tile = game.current_tile.unwrap();
+        println!();
+        println!("where would you place {}?", tile_text(&tile, colored));
+        if hints {
+            print_hint(&game);
+        }
+        let Some(text) = read_console_input("> ") else {
+            println!();
+            return;
+        };
+
+        match input::parse_input(&text, game.rules()) {
+            Ok(Input::Place(field)) => {
+                let before = (game.clone(), opponents.clone());
+                match game.place_tile(field) {
+                    Ok(()) => {
+                        history.push(before);
+                        catch_up(&mut opponents, &game);
+                        print_board(&game.board, colored);
+                        if !opponents.is_empty() {
+                            opponents::print_summaries(&opponents);
+                            opponents::print_scoreboard(&game, &opponents);
+                        }
+                    }
+                    Err(()) => {
+                        if let Err(e) = game.check_placement(&field) {
+                            println!("{}", e);
+                        }
+                    }
+                }
+            }
+            Ok(Input::Undo) => match history.pop() {
+                Some(previous) => {
+                    (game, opponents) = previous;
+                    print_board(&game.board, colored);
+                }
+                None => println!("nothing to undo"),
+            },
+            Ok(Input::Hint) => print_hint(&game),
+            Ok(Input::Board) => print_board(&game.board, colored),
+            Ok(Input::Tiles) => print_tiles(&game, colored),
+            Ok(Input::Opponents) if opponents.is_empty() => println!("no opponents"),
+            Ok(Input::Opponents) => {
+                opponents::print_boards(&opponents, colored);
+                opponents::print_scoreboard(&game, &opponents);
+            }
+            Ok(Input::Help) => println!("{}", input::HELP),
+            Ok(Input::Save(file)) => match std::fs::write(&file, game.save()) {
+                Ok(()) => println!("saved to {}", file),
+                Err(e) => println!("cannot save to {}: {}", file, e),
+            },
+            Ok(Input::Load(file)) => match load_game(Path::new(&file)) {
+                Ok(loaded) => {
+                    game = loaded;
+                    history.clear();
+                    for opponent in opponents.iter_mut() {
+                        opponent.board = Board::with_rules(*game.rules());
+                    }
+                    catch_up(&mut opponents, &game);
+                    print_board(&game.board, colored);
+                }
+                Err(e) => println!("{}", e),
+            },
+            Ok(Input::Quit) => return,
+            Err(e) => println!("{}, type help for the possible input", e),
+        }
+    }
+    println!("game finished! Your score: {}", game.board.score());
+    if !opponents.is_empty() {
+        println!();
+        opponents::print_boards(&opponents, colored);
+        opponents::print_ranking(&game, &opponents);
+    }
+}
+
+#[cfg(feature = "tui")]
+fn play_tui(game: Game, colored: bool) {
+    match tui::run(game, colored) {
+        Ok(score) => println!("your score: {}", score),
+        Err(e) => println!("terminal error: {}", e),
+    }
+}
+
+#[cfg(not(feature = "tui"))]
+fn play_tui(_game: Game, _colored: bool) {
+    println!("built without the tui feature");
+}
+
+fn load_game(path: &Path) -> Result<Game, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Game::load(&text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn new_game(seed: Option<u64>, rules: RuleSet) -> Game {
+    Game::with_rules(rules, seed.unwrap_or_else(rand::random))
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let colored = cli.color.enabled();
+    let rules = match &cli.rules {
+        Some(path) => RuleSet::load(path).unwrap_or_else(|e| {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }),
+        None => RuleSet::default(),
+    };
+    let command = cli.command.unwrap_or(Command::Play {
+        seed: None,
+        hints: false,
+        tui: false,
+        opponents: Vec::new(),
+        resume: None,
+    });
+
+    let result = match command {
+        Command::Play {
+            seed,
+            hints,
+            tui,
+            opponents,
+            resume,
+        } => {
+            let game = match resume {
+                Some(path) => load_game(&path),
+                None => Ok(new_game(seed, rules)),
+            };
+            game.map(|game| {
+                let opponents = opponents
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &depth)| Opponent::new(i + 1, depth, game.rules()))
+                    .collect();
+                if tui {
+                    play_tui(game, colored);
+                } else {
+                    play(game, colored, hints, opponents);
+                }
+            })
+        }
+        Command::Ai {
+            depth,
+            threads,
+            seed,
+        } => {
+            commands::ai(new_game(seed, rules), depth, threads, colored);
+            Ok(())
+        }
+        Command::Simulate {
+            games,
+            depth,
+            threads,
+            seed,
+        } => {
+            let seed = seed.unwrap_or_else(rand::random);
+            commands::simulate(games, depth, threads, seed, rules);
+            Ok(())
+        }
+        Command::Analyze { record, depth } => commands::analyze(&record, depth),
+        Command::Replay { record } => commands::replay(&record, colored),
+        Command::Score { board_file } => commands::score(&board_file, &rules, colored),
+        Command::Engine => {
+            protocol::run_with(io::stdin().lock(), io::stdout(), rules).map_err(|e| e.to_string())
+        }
+        #[cfg(feature = "server")]
+        Command::Serve { address, rooms } => {
+            server::Server::bind(&address).and_then(|server| {
+                let room_server = rooms::RoomServer::bind(&rooms)?;
+                println!("listening on {}, rooms on {}", address, rooms);
+                std::thread::spawn(move || room_server.run());
+                server.run();
+                Ok(())
+            })
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,108 @@
+use takeiteasy::ai::AI;
+use takeiteasy::render::Style;
+use takeiteasy::rules::RuleSet;
+use takeiteasy::tile::{Direction, Tile};
+use takeiteasy::*;
+
+// AI opponents of the console game. They place the same tiles as the player,
+// so their boards follow from the moves of the player's game.
+
+#[derive(Clone)]
+pub struct Opponent {
+    pub name: String,
+    pub depth: i32,
+    pub board: Board,
+}
+
+impl Opponent {
+    pub fn new(number: usize, depth: i32, rules: &RuleSet) -> Opponent {
+        Opponent {
+            name: format!("AI {} (depth {})", number, depth),
+            depth,
+            board: Board::with_rules(*rules),
+        }
+    }
+
+    // places the tiles of the moves the opponent has not placed yet
+    pub fn catch_up(&mut self, moves: &[(Tile, Field)]) {
+        for (tile, _) in &moves[self.board.tiles.len().min(moves.len())..] {
+            let field = AI::best_field(&self.board, *tile, self.depth).unwrap();
+            self.board.place_tile(field, *tile).unwrap();
+        }
+    }
+}
+
+pub fn print_scoreboard(game: &Game, opponents: &[Opponent]) {
+    let mut scores = vec![format!("you {}", game.board.score())];
+    scores.extend(
+        opponents
+            .iter()
+            .map(|o| format!("{} {}", o.name, o.board.score())),
+    );
+    println!("scores: {}", scores.join(" | "));
+}
+
+pub fn print_summaries(opponents: &[Opponent]) {
+    for opponent in opponents {
+        println!("{}: {}", opponent.name, opponent.board.view(Style::Summary));
+    }
+}
+
+pub fn print_boards(opponents: &[Opponent], colored: bool) {
+    for opponent in opponents {
+        println!("{}:", opponent.name);
+        println!("{}", opponent.board.view(Style::Large).colored(colored));
+        println!();
+    }
+}
+
+// scoring lines per direction, e.g. "top 9x5 5x3"
+fn breakdown(board: &Board) -> String {
+    let lines = board.line_scores();
+    [
+        (Direction::Top, "top"),
+        (Direction::Left, "left"),
+        (Direction::Right, "right"),
+    ]
+    .iter()
+    .map(|(direction, name)| {
+        let scoring: Vec<String> = lines
+            .iter()
+            .filter(|line| line.direction == *direction && line.score > 0)
+            .map(|line| {
+                let len = line.fields.len() as u32;
+                format!("{}x{}", line.score / len, len)
+            })
+            .collect();
+        if scoring.is_empty() {
+            format!("{} -", name)
+        } else {
+            format!("{} {}", name, scoring.join(" "))
+        }
+    })
+    .collect::<Vec<String>>()
+    .join(", ")
+}
+
+pub fn print_ranking(game: &Game, opponents: &[Opponent]) {
+    let mut players: Vec<(&str, &Board)> = vec![("you", &game.board)];
+    players.extend(opponents.iter().map(|o| (o.name.as_str(), &o.board)));
+    players.sort_by_key(|(_, board)| std::cmp::Reverse(board.score()));
+    let width = players
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0);
+
+    println!("final ranking:");
+    for (i, (name, board)) in players.iter().enumerate() {
+        println!(
+            "{}. {:<width$} {:>3}   {}",
+            i + 1,
+            name,
+            board.score(),
+            breakdown(board),
+            width = width
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use crossterm::event::{
+    self, Event, KeyCode, KeyEventKind, MouseButton, MouseEvent, MouseEventKind,
+};
+use crossterm::style::Print;
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, execute, queue};
+use std::io::{self, Write};
+use takeiteasy::ai::AI;
+use takeiteasy::hex::Hex;
+use takeiteasy::record::format_tile;
+use takeiteasy::render::{large_field_at_with, large_position_with, Style};
+use takeiteasy::rules::RuleSet;
+use takeiteasy::tile::Tile;
+use takeiteasy::*;
+
+// Full-screen game: the board on the left, score and remaining tiles on the
+// right. The current tile is previewed on the field under the cursor.
+
+// space between the right edge of the board and the panel
+const PANEL_GAP: usize = 8;
+const HINT_DEPTH: i32 = 1;
+
+struct Tui {
+    game: Game,
+    // games before each move, for undo
+    history: Vec<Game>,
+    cursor: Field,
+    message: String,
+    colored: bool,
+}
+
+// leaves raw mode and the alternate screen also on errors and panics
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> io::Result<TerminalGuard> {
+        terminal::enable_raw_mode()?;
+        execute!(
+            io::stdout(),
+            terminal::EnterAlternateScreen,
+            event::EnableMouseCapture,
+            cursor::Hide
+        )?;
+        Ok(TerminalGuard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(
+            io::stdout(),
+            cursor::Show,
+            event::DisableMouseCapture,
+            terminal::LeaveAlternateScreen
+        );
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn remaining_tiles(game: &Game) -> Vec<Tile> {
+    let mut tiles: Vec<Tile> = game
+        .board
+        .remaining_tiles()
+        .into_iter()
+        .filter(|tile| Some(*tile) != game.current_tile)
+        .collect();
+    tiles.sort();
+    tiles
+}
+
+// first of the steps that stays on the board, sideways the upper neighbour
+// is preferred
+fn step(field: &Field, steps: &[Hex], rules: &RuleSet) -> Option<Field> {
+    let hex = rules.hex(field);
+    steps
+        .iter()
+        .find_map(|step| (hex + *step).to_field(rules.radius()))
+}
+
+impl Tui {
+    fn new(game: Game, colored: bool) -> Tui {
+        let center = Hex::CENTER.to_field(game.rules().radius()).unwrap();
+        Tui {
+            game,
+            history: Vec::new(),
+            cursor: center,
+            message: String::new(),
+            colored,
+        }
+    }
+
+    fn move_cursor(&mut self, code: KeyCode) {
+        let rules = self.game.rules();
+        let steps: &[Hex] = match code {
+            KeyCode::Up => &[Hex::UP],
+            KeyCode::Down => &[Hex::DOWN],
+            KeyCode::Left => &[Hex::UP_LEFT, Hex::DOWN_LEFT],
+            KeyCode::Right => &[Hex::UP_RIGHT, Hex::DOWN_RIGHT],
+            _ => &[],
+        };
+        let next = step(&self.cursor, steps, rules);
+        if let Some(field) = next {
+            self.cursor = field;
+        }
+    }
+
+    fn place(&mut self) {
+        let Some(tile) = self.game.current_tile else {
+            return;
+        };
+        let before = self.game.clone();
+        match self.game.place_tile(self.cursor) {
+            Ok(()) => {
+                self.history.push(before);
+                self.message = format!("placed {} at {}", tile, self.cursor);
+                if self.game.finished() {
+                    self.message =
+                        format!("game finished! Your score: {}", self.game.board.score());
+                }
+            }
+            Err(()) => {
+                if let Err(e) = self.game.check_placement(&self.cursor) {
+                    self.message = e;
+                }
+            }
+        }
+    }
+
+    fn undo(&mut self) {
+        match self.history.pop() {
+            Some(game) => {
+                self.game = game;
+                self.message = "undone".to_string();
+            }
+            None => self.message = "nothing to undo".to_string(),
+        }
+    }
+
+    fn hint(&mut self) {
+        let Some(tile) = self.game.current_tile else {
+            return;
+        };
+        match AI::best_field(&self.game.board, tile, HINT_DEPTH) {
+            Ok(field) => {
+                self.cursor = field;
+                self.message = format!("hint: place {} at {}", tile, field);
+            }
+            Err(()) => self.message = "no hint".to_string(),
+        }
+    }
+
+    fn click(&mut self, mouse: MouseEvent) {
+        let (line, column) = (mouse.row as usize, mouse.column as usize);
+        if let Some(field) = large_field_at_with(line, column, self.game.rules()) {
+            // a second click on the same field places the tile
+            if field == self.cursor {
+                self.place();
+            } else {
+                self.cursor = field;
+            }
+        }
+    }
+
+    fn tile_text(&self, tile: &Tile) -> String {
+        if self.colored {
+            tile.colored()
+        } else {
+            tile.to_string()
+        }
+    }
+
+    fn panel(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        lines.push(match &self.game.current_tile {
+            Some(tile) => format!("Tile:       {}", self.tile_text(tile)),
+            None => "Tile:       -".to_string(),
+        });
+        lines.push(format!("Score:      {}", self.game.board.score()));
+        lines.push(format!("Potential:  {}", self.game.board.max_score()));
+        lines.push(String::new());
+
+        let remaining = remaining_tiles(&self.game);
+        lines.push(format!("Remaining tiles ({}):", remaining.len()));
+        for chunk in remaining.chunks(6) {
+            let tiles: Vec<String> = chunk.iter().map(format_tile).collect();
+            lines.push(format!("  {}", tiles.join(" ")));
+        }
+        lines.push(String::new());
+
+        lines.push("arrows/mouse  move".to_string());
+        lines.push("enter/space   place".to_string());
+        lines.push("u undo   h hint   q quit".to_string());
+        lines
+    }
+
+    fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        // preview of the current tile on the cursor field
+        let mut board = self.game.board.clone();
+        if let Some(tile) = self.game.current_tile {
+            let _ = board.place_tile(self.cursor, tile);
+        }
+        let view = board
+            .view(Style::Large)
+            .colored(self.colored)
+            .labels(true)
+            .highlight_field(self.cursor)
+            .to_string();
+
+        queue!(out, terminal::Clear(ClearType::All))?;
+        for (i, line) in view.lines().enumerate() {
+            queue!(out, cursor::MoveTo(0, i as u16), Print(line))?;
+        }
+        // right of the numbers of the last column, 5 more to its edge
+        let rules = self.game.rules();
+        let last = field!(rules.num_columns(), 1);
+        let panel_column = (large_position_with(&last, rules).1 + 5 + PANEL_GAP) as u16;
+        for (i, line) in self.panel().iter().enumerate() {
+            queue!(out, cursor::MoveTo(panel_column, 2 + i as u16), Print(line))?;
+        }
+        let last_line = view.lines().count() as u16 + 1;
+        queue!(out, cursor::MoveTo(0, last_line), Print(&self.message))?;
+        out.flush()
+    }
+
+    fn run(&mut self) -> io::Result<()> {
+        let _guard = TerminalGuard::enter()?;
+        let mut out = io::stdout();
+        loop {
+            self.draw(&mut out)?;
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char('u') => self.undo(),
+                    KeyCode::Char('h') => self.hint(),
+                    KeyCode::Enter | KeyCode::Char(' ') => self.place(),
+                    code => self.move_cursor(code),
+                },
+                Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                    self.click(mouse)
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+pub fn run(game: Game, colored: bool) -> io::Result<u32> {
+    let mut tui = Tui::new(game, colored);
+    tui.run()?;
+    Ok(tui.game.board.score())
+}
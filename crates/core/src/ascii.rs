@@ -0,0 +1,131 @@
+use crate::hex::Hex;
+use crate::record::ParseError;
+use crate::rules::RuleSet;
+use crate::tile::{Direction, Tile};
+use crate::{Board, Field};
+
+// Reading boards back from the drawing of `impl Display for Board`.
+//
+// The drawing is found by the top edge of the middle column (`_______`), so
+// the leading newline and indentation may be missing as long as all lines are
+// shifted alike. Labels of a drawing with `BoardView::labels` are skipped,
+// the id of an empty field is read like a blank.
+
+const ANCHOR: &str = "_______";
+const ANCHOR_LINE: usize = 1;
+
+// (line, column) of the top number of a field in the drawing of a board with
+// the given radius, the left and right number are one line below, two columns
+// to the left and right
+pub(crate) fn ascii_position(field: &Field, radius: u32) -> (usize, usize) {
+    // half a field down per step to the right, two lines per half field
+    let hex = Hex::from_field(field, radius);
+    let radius = radius as i32;
+    (
+        (3 + 2 * (2 * hex.r + hex.q + 2 * radius)) as usize,
+        (17 + 9 * (hex.q + radius)) as usize,
+    )
+}
+
+// column of the anchor in the drawing, above the middle column
+fn drawing_anchor(radius: u32) -> usize {
+    14 + 9 * radius as usize
+}
+
+impl Board {
+    // a board of the classic game
+    pub fn from_ascii(text: &str) -> Result<Board, ParseError> {
+        Board::from_ascii_with(text, &RuleSet::default())
+    }
+
+    pub fn from_ascii_with(text: &str, rules: &RuleSet) -> Result<Board, ParseError> {
+        let lines: Vec<Vec<char>> = text.lines().map(|l| l.chars().collect()).collect();
+        let error = |line: usize, column: usize, message: String| ParseError {
+            line: line + 1,
+            column: column + 1,
+            message,
+        };
+
+        let (anchor_line, anchor_column) = lines
+            .iter()
+            .enumerate()
+            .find_map(|(i, line)| {
+                let line: String = line.iter().collect();
+                line.find(ANCHOR)
+                    .map(|byte| (i, line[..byte].chars().count()))
+            })
+            .ok_or_else(|| error(0, 0, "no board found".to_string()))?;
+        // the leftmost number is at column 15
+        let radius = rules.radius();
+        if anchor_column + 15 < drawing_anchor(radius) {
+            return Err(error(
+                anchor_line,
+                anchor_column,
+                "board is cut off".to_string(),
+            ));
+        }
+        let shift_line = |line: usize| line + anchor_line - ANCHOR_LINE;
+        let shift_column = |column: usize| column + anchor_column - drawing_anchor(radius);
+
+        // the last numbers are on the line above the bottom edge
+        let last_line = rules
+            .fields()
+            .iter()
+            .map(|field| shift_line(ascii_position(field, radius).0 + 1))
+            .max()
+            .unwrap();
+        if lines.len() <= last_line {
+            return Err(error(lines.len(), 0, "board ends early".to_string()));
+        }
+
+        let mut board = Board::with_rules(*rules);
+        for field in rules.fields() {
+            let (line, column) = ascii_position(&field, radius);
+            let (line, column) = (shift_line(line), shift_column(column));
+            let positions = [
+                (line, column),
+                (line + 1, column - 2),
+                (line + 1, column + 2),
+            ];
+
+            let id = field.id_with(rules);
+            let mut numbers = [None; 3];
+            for (i, (number, &(line, column))) in numbers.iter_mut().zip(&positions).enumerate() {
+                match lines[line].get(column).copied().unwrap_or(' ') {
+                    ' ' => {}
+                    c if i == 0 && Some(c) == id => {}
+                    c => match c.to_digit(10) {
+                        Some(digit) => *number = Some(digit),
+                        None => return Err(error(line, column, format!("unexpected '{}'", c))),
+                    },
+                }
+            }
+
+            let tile = match numbers {
+                [None, None, None] => continue,
+                [Some(top), Some(left), Some(right)] => {
+                    let check = |i: usize, direction: Direction, number: u32| {
+                        let (line, column) = positions[i];
+                        rules
+                            .check_number(&direction, number)
+                            .map_err(|e| error(line, column, e))
+                    };
+                    Tile {
+                        top: check(0, Direction::Top, top)?,
+                        left: check(1, Direction::Left, left)?,
+                        right: check(2, Direction::Right, right)?,
+                    }
+                }
+                _ => {
+                    let i = numbers.iter().position(|n| n.is_none()).unwrap();
+                    let (line, column) = positions[i];
+                    return Err(error(line, column, format!("incomplete tile on {}", field)));
+                }
+            };
+            if board.place_tile(field, tile).is_err() {
+                return Err(error(line, column, format!("{} is used twice", tile)));
+            }
+        }
+        Ok(board)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::tile::{number_color, Direction, Tile};
+use std::ffi::OsStr;
+use std::io::IsTerminal;
+
+// ANSI colored output, every number in the color of its bar in the physical
+// game. Colors are only used if stdout is a terminal and `NO_COLOR` is not set.
+
+pub const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Emphasis {
+    #[default]
+    Normal,
+    // the number is part of a completed line
+    Bold,
+    // the number is part of a line that cannot score anymore
+    Dim,
+}
+
+// a flag like --color=always/never wins over the value of `NO_COLOR`, which
+// turns colors off unless it is empty (see https://no-color.org)
+pub fn color_choice(flag: Option<bool>, no_color: Option<&OsStr>, is_terminal: bool) -> bool {
+    match flag {
+        Some(enabled) => enabled,
+        None => no_color.is_none_or(|value| value.is_empty()) && is_terminal,
+    }
+}
+
+pub fn color_enabled() -> bool {
+    color_choice(
+        None,
+        std::env::var_os("NO_COLOR").as_deref(),
+        std::io::stdout().is_terminal(),
+    )
+}
+
+pub fn escape(number: u32, emphasis: Emphasis) -> String {
+    let (r, g, b) = number_color(number);
+    let attribute = match emphasis {
+        Emphasis::Normal => "",
+        Emphasis::Bold => "1;",
+        Emphasis::Dim => "2;",
+    };
+    format!("\x1b[{}38;2;{};{};{}m", attribute, r, g, b)
+}
+
+pub fn paint(text: &str, number: u32, emphasis: Emphasis) -> String {
+    format!("{}{}{}", escape(number, emphasis), text, RESET)
+}
+
+impl Tile {
+    // like `Display`, with colored numbers
+    pub fn colored(&self) -> String {
+        let numbers: Vec<String> = [Direction::Top, Direction::Left, Direction::Right]
+            .iter()
+            .map(|direction| {
+                let number = self.number(direction);
+                paint(&number.to_string(), number, Emphasis::Normal)
+            })
+            .collect();
+        format!("Tile({})", numbers.join(" "))
+    }
+}
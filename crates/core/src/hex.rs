@@ -0,0 +1,166 @@
+use crate::tile::Direction;
+use crate::{Coordinate, Field};
+use core::ops::{Add, Mul, Sub};
+
+// Axial coordinates of the hexagons of a board, with the center at (0, 0).
+//
+// `q` counts the columns from the middle column, negative to the left, and
+// `r` grows from top to bottom within a column. The third cube coordinate
+// `s` is -q - r. On a board of radius n every hexagon has a distance of at
+// most n to the center.
+//
+// The lines of a direction keep one coordinate: top lines are the columns
+// (same q), right lines keep r and left lines keep s. Fields are the same
+// hexagons counted by column and row from the top left, so a field depends
+// on the radius of its board.
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash, Debug)]
+pub struct Hex {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl Hex {
+    pub const CENTER: Hex = Hex { q: 0, r: 0 };
+
+    // steps to the neighbours, clockwise from the top
+    pub const UP: Hex = Hex { q: 0, r: -1 };
+    pub const UP_RIGHT: Hex = Hex { q: 1, r: -1 };
+    pub const DOWN_RIGHT: Hex = Hex { q: 1, r: 0 };
+    pub const DOWN: Hex = Hex { q: 0, r: 1 };
+    pub const DOWN_LEFT: Hex = Hex { q: -1, r: 1 };
+    pub const UP_LEFT: Hex = Hex { q: -1, r: 0 };
+    pub const STEPS: [Hex; 6] = [
+        Hex::UP,
+        Hex::UP_RIGHT,
+        Hex::DOWN_RIGHT,
+        Hex::DOWN,
+        Hex::DOWN_LEFT,
+        Hex::UP_LEFT,
+    ];
+
+    pub fn new(q: i32, r: i32) -> Hex {
+        Hex { q, r }
+    }
+
+    pub fn s(&self) -> i32 {
+        -self.q - self.r
+    }
+
+    pub fn neighbors(&self) -> [Hex; 6] {
+        Hex::STEPS.map(|step| *self + step)
+    }
+
+    pub fn distance(&self, other: &Hex) -> i32 {
+        let d = *self - *other;
+        (d.q.abs() + d.r.abs() + d.s().abs()) / 2
+    }
+
+    // distance to the center
+    pub fn length(&self) -> i32 {
+        self.distance(&Hex::CENTER)
+    }
+
+    // step from one hexagon of a line to the next, from top to bottom for top
+    // lines and from left to right for the others
+    pub fn step(direction: &Direction) -> Hex {
+        match direction {
+            Direction::Top => Hex::DOWN,
+            Direction::Left => Hex::UP_RIGHT,
+            Direction::Right => Hex::DOWN_RIGHT,
+        }
+    }
+
+    // the coordinate all hexagons of a line in the direction share, counting
+    // the lines from left to right for top lines and from top to bottom for
+    // the others
+    pub fn line(&self, direction: &Direction) -> i32 {
+        match direction {
+            Direction::Top => self.q,
+            Direction::Left => -self.s(),
+            Direction::Right => self.r,
+        }
+    }
+
+    // 60° steps around the center, clockwise for positive steps
+    pub fn rotate(&self, steps: i32) -> Hex {
+        let mut hex = *self;
+        for _ in 0..steps.rem_euclid(6) {
+            hex = Hex {
+                q: -hex.r,
+                r: -hex.s(),
+            };
+        }
+        hex
+    }
+
+    // mirrored at the middle column, left and right lines swap
+    pub fn reflect(&self) -> Hex {
+        Hex {
+            q: -self.q,
+            r: -self.s(),
+        }
+    }
+
+    // row 1 of a column
+    fn top(q: i32, radius: i32) -> i32 {
+        (-radius).max(-radius - q)
+    }
+
+    pub fn from_field(field: &Field, radius: u32) -> Hex {
+        let q = field.column as i32 - radius as i32 - 1;
+        Hex {
+            q,
+            r: Hex::top(q, radius as i32) + field.row as i32 - 1,
+        }
+    }
+
+    // None off the board
+    pub fn to_field(&self, radius: u32) -> Option<Field> {
+        if self.length() > radius as i32 {
+            return None;
+        }
+        Some(Field {
+            column: Coordinate::from_int(self.q + radius as i32 + 1).ok()?,
+            row: Coordinate::from_int(self.r - Hex::top(self.q, radius as i32) + 1).ok()?,
+        })
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, other: Hex) -> Hex {
+        Hex::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Mul<i32> for Hex {
+    type Output = Hex;
+
+    fn mul(self, factor: i32) -> Hex {
+        Hex::new(self.q * factor, self.r * factor)
+    }
+}
+
+// the hexagons of a board, by column and row like the fields
+pub fn hexes(radius: u32) -> impl Iterator<Item = Hex> {
+    let radius = radius as i32;
+    (-radius..=radius).flat_map(move |q| {
+        let top = Hex::top(q, radius);
+        (top..top + 2 * radius + 1 - q.abs()).map(move |r| Hex { q, r })
+    })
+}
+
+// the hexagons of a line on a board, in the order of `Hex::step`
+pub fn line(direction: &Direction, line: i32, radius: u32) -> impl Iterator<Item = Hex> + '_ {
+    hexes(radius).filter(move |hex| hex.line(direction) == line)
+}
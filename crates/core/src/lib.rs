@@ -0,0 +1,545 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Rules and scoring, re-exported by the `takeiteasy` crate.
+//
+// Without the `std` feature the crate is `no_std` and needs only `alloc`: the
+// rules (tiles, fields, boards, scoring), games with a given seed and game
+// records. Everything else needs `std`.
+//
+// Breaking change in 0.2: `Board::tiles` is a `BTreeMap`, and
+// `Board::all_fields`, `Board::empty_fields`, `Board::remaining_tiles`,
+// `TileReservoir::remaining_tiles` and `TileReservoir::all_tiles` are
+// `BTreeSet`s, they were `HashMap` and `HashSet` in 0.1. They are ordered and
+// work without `std`. Code that names the hash types has to switch to the
+// ordered ones or collect into them. The numbers of a `Tile` are `u32`s
+// instead of `NumTop`, `NumLeft` and `NumRight`, which are deprecated:
+// `Tile::classic` takes the enums and `u32::from` converts them.
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod ascii;
+#[cfg(feature = "std")]
+pub mod color;
+pub mod hex;
+pub mod record;
+#[cfg(feature = "std")]
+pub mod render;
+pub mod rules;
+#[cfg(feature = "std")]
+mod save;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "std")]
+pub mod session;
+#[cfg(feature = "render-svg")]
+pub mod svg;
+pub mod tile;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+use hex::Hex;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rules::RuleSet;
+#[cfg(feature = "std")]
+pub use save::SAVE_VERSION;
+use strum::IntoEnumIterator;
+use tile::{Direction, Tile};
+
+#[repr(i32)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash, Debug)]
+pub enum Coordinate {
+    C1 = 1,
+    C2 = 2,
+    C3 = 3,
+    C4 = 4,
+    C5 = 5,
+    C6 = 6,
+    C7 = 7,
+}
+
+impl Coordinate {
+    #[allow(clippy::result_unit_err)]
+    pub fn from_int(num: i32) -> Result<Coordinate, ()> {
+        match num {
+            1 => Ok(Coordinate::C1),
+            2 => Ok(Coordinate::C2),
+            3 => Ok(Coordinate::C3),
+            4 => Ok(Coordinate::C4),
+            5 => Ok(Coordinate::C5),
+            6 => Ok(Coordinate::C6),
+            7 => Ok(Coordinate::C7),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct Field {
+    pub column: Coordinate,
+    pub row: Coordinate,
+}
+
+impl Field {
+    // a field of the classic board, see `RuleSet::field` for other boards
+    pub fn new(column: Coordinate, row: Coordinate) -> Result<Field, String> {
+        RuleSet::default().field(column, row)
+    }
+
+    // letter a to s, counting the fields of the classic board column by column
+    pub fn id(&self) -> char {
+        self.id_with(&RuleSet::default()).unwrap_or('?')
+    }
+
+    // only boards with up to 26 fields have ids
+    pub fn id_with(&self, rules: &RuleSet) -> Option<char> {
+        if rules.num_fields() > 26 {
+            return None;
+        }
+        let index = rules.fields().iter().position(|field| field == self)?;
+        Some((b'a' + index as u8) as char)
+    }
+
+    #[allow(clippy::result_unit_err)]
+    pub fn from_id(id: char) -> Result<Field, ()> {
+        Field::from_id_with(id, &RuleSet::default())
+    }
+
+    #[allow(clippy::result_unit_err)]
+    pub fn from_id_with(id: char, rules: &RuleSet) -> Result<Field, ()> {
+        rules
+            .fields()
+            .into_iter()
+            .find(|field| field.id_with(rules) == Some(id.to_ascii_lowercase()))
+            .ok_or(())
+    }
+
+    // like `parse`, with the fields of the board of the rule set
+    pub fn parse_with(text: &str, rules: &RuleSet) -> Result<Field, String> {
+        let text = text.trim().to_ascii_lowercase();
+        let invalid = || format!("invalid field: {}", text);
+
+        let name: String = text.chars().filter(|c| c.is_alphanumeric()).collect();
+        if let Some((_, hex)) = field_names(rules).iter().find(|(n, _)| *n == name) {
+            return hex.to_field(rules.radius()).ok_or_else(invalid);
+        }
+
+        let mut chars = text.chars();
+        if let (Some(id), None) = (chars.next(), chars.next()) {
+            if id.is_ascii_alphabetic() {
+                return Field::from_id_with(id, rules).map_err(|_| invalid());
+            }
+        }
+
+        if text.starts_with('c') {
+            return record::parse_field_with(&text, rules).map_err(|_| invalid());
+        }
+
+        let numbers: Vec<&str> = text
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|part| !part.is_empty())
+            .collect();
+        let [column, row] = numbers[..] else {
+            return Err(invalid());
+        };
+        let coordinate = |number: &str| {
+            number
+                .parse::<i32>()
+                .ok()
+                .and_then(|n| Coordinate::from_int(n).ok())
+                .ok_or_else(invalid)
+        };
+        rules.field(coordinate(column)?, coordinate(row)?)
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Field({} {})", self.column as i32, self.row as i32)
+    }
+}
+
+#[macro_export]
+macro_rules! field {
+    ($column:expr, $row:expr) => {
+        Field {
+            column: Coordinate::from_int($column).unwrap(),
+            row: Coordinate::from_int($row).unwrap(),
+        }
+    };
+}
+
+// names of the center and the corners of the board
+fn field_names(rules: &RuleSet) -> [(&'static str, Hex); 7] {
+    let radius = rules.radius() as i32;
+    [
+        ("center", Hex::CENTER),
+        ("top", Hex::UP * radius),
+        ("bottom", Hex::DOWN * radius),
+        ("topleft", Hex::UP_LEFT * radius),
+        ("bottomleft", Hex::DOWN_LEFT * radius),
+        ("topright", Hex::UP_RIGHT * radius),
+        ("bottomright", Hex::DOWN_RIGHT * radius),
+    ]
+}
+
+// accepts "3 2", "3,2", "c3r2", the id "i" (see `Field::id`) and names like
+// "center" or "top-left", on the classic board
+impl FromStr for Field {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Field, String> {
+        Field::parse_with(text, &RuleSet::default())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineScore {
+    pub direction: Direction,
+    pub fields: Vec<Field>,
+    pub score: u32,
+    // two different numbers, the line cannot score anymore
+    pub broken: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Board {
+    pub tiles: BTreeMap<Field, Option<Tile>>,
+    rules: RuleSet,
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Board::new()
+    }
+}
+
+impl Board {
+    pub fn new() -> Board {
+        Board::with_rules(RuleSet::default())
+    }
+
+    pub fn with_rules(rules: RuleSet) -> Board {
+        Board {
+            tiles: BTreeMap::new(),
+            rules,
+        }
+    }
+
+    pub fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+
+    // fields of the classic board
+    pub fn all_fields() -> BTreeSet<Field> {
+        RuleSet::default().fields()
+    }
+
+    pub fn fields(&self) -> BTreeSet<Field> {
+        self.rules.fields()
+    }
+
+    pub fn remaining_tiles(&self) -> BTreeSet<Tile> {
+        let mut reservoir = TileReservoir::with_rules(&self.rules);
+        for tile in self.tiles.values() {
+            let tile = tile.unwrap();
+            assert!(reservoir.pick_tile(&tile).is_ok());
+        }
+        reservoir.remaining_tiles
+    }
+
+    pub fn empty_fields(&self) -> BTreeSet<Field> {
+        let mut fields = BTreeSet::new();
+        for field in self.fields() {
+            if !self.tiles.contains_key(&field) {
+                fields.insert(field);
+            }
+        }
+        fields
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.empty_fields().is_empty()
+    }
+
+    #[allow(clippy::result_unit_err)]
+    pub fn place_tile(&mut self, field: Field, tile: Tile) -> Result<(), ()> {
+        if self.rules.field(field.column, field.row).is_err() {
+            return Err(());
+        }
+        if self.tiles.contains_key(&field) {
+            return Err(());
+        }
+        if !self.rules.contains(&tile) {
+            return Err(());
+        }
+        if self.tiles.values().any(|t| t == &Some(tile)) {
+            return Err(());
+        }
+        self.tiles.insert(field, Some(tile));
+        Ok(())
+    }
+
+    #[allow(clippy::result_unit_err)]
+    pub fn place_tile_on_new_board(&self, field: Field, tile: Tile) -> Result<Board, ()> {
+        let mut new_board: Board = self.clone();
+        new_board.place_tile(field, tile).map(|_| new_board)
+    }
+
+    // fields of the scoring lines in one direction on the classic board
+    pub fn score_sections(direction: &Direction) -> Vec<Vec<Field>> {
+        RuleSet::default().lines(direction)
+    }
+
+    fn section_score(&self, direction: Direction) -> u32 {
+        let mut score: u32 = 0;
+        for section in self.rules.lines(&direction) {
+            let numbers: Vec<u32> = section
+                .iter()
+                .map(|field| match self.tiles.get(field) {
+                    Some(tile) => tile.unwrap().number(&direction),
+                    None => 0,
+                })
+                .collect();
+
+            if all_elements_equal(&numbers) {
+                score += numbers.first().unwrap() * section.len() as u32;
+            }
+        }
+        score
+    }
+
+    fn section_score_max(&self, direction: Direction) -> u32 {
+        let mut score: u32 = 0;
+        for section in self.rules.lines(&direction) {
+            let numbers_except_0: Vec<u32> = section
+                .iter()
+                .map(|field| match self.tiles.get(field) {
+                    Some(tile) => tile.unwrap().number(&direction),
+                    None => 0,
+                })
+                .filter(|number| *number != 0)
+                .collect();
+
+            if numbers_except_0.is_empty() {
+                score += self.rules.max_number(&direction) * section.len() as u32;
+            } else if all_elements_equal(&numbers_except_0) {
+                score += numbers_except_0.first().unwrap() * section.len() as u32;
+            }
+        }
+        score
+    }
+
+    pub fn max_score(&self) -> u32 {
+        self.section_score_max(Direction::Top)
+            + self.section_score_max(Direction::Left)
+            + self.section_score_max(Direction::Right)
+    }
+
+    pub fn score(&self) -> u32 {
+        self.section_score(Direction::Top)
+            + self.section_score(Direction::Left)
+            + self.section_score(Direction::Right)
+    }
+
+    // every line of the board with its current score
+    pub fn line_scores(&self) -> Vec<LineScore> {
+        let mut lines = Vec::new();
+        for direction in Direction::iter() {
+            for section in self.rules.lines(&direction) {
+                let numbers: Vec<u32> = section
+                    .iter()
+                    .filter_map(|field| self.tiles.get(field).and_then(|tile| *tile))
+                    .map(|tile| tile.number(&direction))
+                    .collect();
+                let broken = !all_elements_equal(&numbers);
+                let score = if !broken && numbers.len() == section.len() {
+                    numbers[0] * section.len() as u32
+                } else {
+                    0
+                };
+                lines.push(LineScore {
+                    direction,
+                    fields: section,
+                    score,
+                    broken,
+                });
+            }
+        }
+        lines
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.view(render::Style::Large))
+    }
+}
+
+fn all_elements_equal<T: PartialEq>(vec: &[T]) -> bool {
+    if let Some(first) = vec.first() {
+        vec.iter().all(|x| x == first)
+    } else {
+        true // An empty vector is considered to have all equal elements
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TileReservoir {
+    pub remaining_tiles: BTreeSet<Tile>,
+}
+
+impl Default for TileReservoir {
+    fn default() -> Self {
+        TileReservoir::new()
+    }
+}
+
+impl TileReservoir {
+    pub fn new() -> TileReservoir {
+        TileReservoir::with_rules(&RuleSet::default())
+    }
+
+    pub fn with_rules(rules: &RuleSet) -> TileReservoir {
+        TileReservoir {
+            remaining_tiles: rules.tiles(),
+        }
+    }
+
+    // the tiles of the classic game
+    pub fn all_tiles() -> BTreeSet<Tile> {
+        RuleSet::default().tiles()
+    }
+
+    #[allow(clippy::result_unit_err)]
+    pub fn pick_tile(&mut self, tile: &Tile) -> Result<(), ()> {
+        if !self.remaining_tiles.contains(tile) {
+            return Err(());
+        }
+        self.remaining_tiles.retain(|&t| &t != tile);
+        Ok(())
+    }
+
+    #[cfg(feature = "entropy")]
+    #[allow(clippy::result_unit_err)]
+    pub fn pick_random_tile(&mut self) -> Result<Tile, ()> {
+        self.pick_random_tile_with(&mut rand::thread_rng())
+    }
+
+    // the same rng state always picks the same tile
+    #[allow(clippy::result_unit_err)]
+    pub fn pick_random_tile_with<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Result<Tile, ()> {
+        if self.remaining_tiles.is_empty() {
+            return Err(());
+        }
+        let index = rng.gen_range(0..self.remaining_tiles.len());
+        let tile = *self.remaining_tiles.iter().nth(index).unwrap();
+        self.pick_tile(&tile).map(|_| tile)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub board: Board,
+    tile_reservoir: TileReservoir,
+    pub current_tile: Option<Tile>,
+    moves: Vec<(Tile, Field)>,
+    seed: u64,
+    rng: ChaCha8Rng,
+}
+
+#[cfg(feature = "entropy")]
+impl Default for Game {
+    fn default() -> Self {
+        Game::new()
+    }
+}
+
+impl Game {
+    #[cfg(feature = "entropy")]
+    pub fn new() -> Game {
+        Game::with_seed(rand::thread_rng().gen())
+    }
+
+    // games with the same seed draw the same tiles
+    pub fn with_seed(seed: u64) -> Game {
+        Game::with_rules(RuleSet::default(), seed)
+    }
+
+    pub fn with_rules(rules: RuleSet, seed: u64) -> Game {
+        let board = Board::with_rules(rules);
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut tile_reservoir = TileReservoir::with_rules(&rules);
+        let current_tile = tile_reservoir.pick_random_tile_with(&mut rng).unwrap();
+        Game {
+            board,
+            tile_reservoir,
+            current_tile: Some(current_tile),
+            moves: Vec::new(),
+            seed,
+            rng,
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn rules(&self) -> &RuleSet {
+        self.board.rules()
+    }
+
+    // replace the drawn tile by a chosen one, e.g. when replaying a record
+    #[allow(clippy::result_unit_err)]
+    pub fn set_current_tile(&mut self, tile: Tile) -> Result<(), ()> {
+        if self.current_tile == Some(tile) {
+            return Ok(());
+        }
+        self.tile_reservoir.pick_tile(&tile)?;
+        if let Some(previous) = self.current_tile {
+            self.tile_reservoir.remaining_tiles.insert(previous);
+        }
+        self.current_tile = Some(tile);
+        Ok(())
+    }
+
+    #[allow(clippy::result_unit_err)]
+    pub fn place_tile(&mut self, field: Field) -> Result<(), ()> {
+        let Some(tile) = self.current_tile else {
+            return Err(());
+        };
+        if self.board.place_tile(field, tile).is_err() {
+            return Err(());
+        }
+        self.moves.push((self.current_tile.unwrap(), field));
+        self.current_tile = self.tile_reservoir.pick_random_tile_with(&mut self.rng).ok();
+        Ok(())
+    }
+
+    // why `place_tile` would fail on the field
+    pub fn check_placement(&self, field: &Field) -> Result<(), String> {
+        if self.current_tile.is_none() {
+            return Err(String::from("the game is finished"));
+        }
+        if self.board.rules().field(field.column, field.row).is_err() {
+            return Err(format!("{} is not on the board", field));
+        }
+        if self.board.tiles.contains_key(field) {
+            return Err(format!("{} is not empty", field));
+        }
+        Ok(())
+    }
+
+    pub fn moves(&self) -> &[(Tile, Field)] {
+        &self.moves
+    }
+
+    pub fn finished(&self) -> bool {
+        self.board.is_full()
+    }
+}
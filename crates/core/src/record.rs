@@ -0,0 +1,468 @@
+use crate::rules::RuleSet;
+use crate::tile::Tile;
+use crate::{Board, Coordinate, Field, Game};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+// Text notation for complete game records:
+//
+//     [Date "2024-05-01"]
+//     [Players "Alice"]
+//     [Seed "42"]
+//     [Ruleset "classic"]
+//
+//     { game comment }
+//     973 C1R1
+//     924 C1R2 { comment on the move }
+//     ...
+//     score 180
+//
+// Tiles are written as their top, left and right number, fields as column
+// and row. Tags, comments and the final score are optional. Tag names are
+// letters, digits and underscores. The Ruleset tag is "classic" or the
+// numbers and radius of a variant, e.g. "259 167 348" or "159 267 348 r1"
+// (see `RuleSet`), the Seed tag the seed of the game, which draws the tiles
+// after the moves of an unfinished record. In tag values `\"` stands for a quote,
+// in comments `\}` for a closing brace and in both `\\` for a backslash and
+// `\n` for a line break. A score must be the one of the moves.
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Move {
+    pub tile: Tile,
+    pub field: Field,
+    pub comment: Option<String>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct GameRecord {
+    pub tags: Vec<(String, String)>,
+    pub comment: Option<String>,
+    pub moves: Vec<Move>,
+    pub score: Option<u32>,
+}
+
+// backslash before itself and `special`, line breaks as "\n"
+fn escape(text: &str, special: char) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '\n' => escaped.push_str("\\n"),
+            '\\' => escaped.push_str("\\\\"),
+            c if c == special => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn check_tag_name(name: &str) -> Result<(), String> {
+    if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        Ok(())
+    } else {
+        Err(format!("invalid tag name: {}", name))
+    }
+}
+
+// None for a backslash at the end
+fn unescape(text: &str) -> Option<String> {
+    let mut unescaped = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        unescaped.push(match c {
+            '\\' => match chars.next()? {
+                'n' => '\n',
+                c => c,
+            },
+            c => c,
+        });
+    }
+    Some(unescaped)
+}
+
+pub fn format_tile(tile: &Tile) -> String {
+    format!("{}{}{}", tile.top, tile.left, tile.right)
+}
+
+// a tile of the classic game
+pub fn parse_tile(text: &str) -> Result<Tile, String> {
+    parse_tile_with(text, &RuleSet::default())
+}
+
+pub fn parse_tile_with(text: &str, rules: &RuleSet) -> Result<Tile, String> {
+    let digits: Vec<u32> = text
+        .chars()
+        .map(|c| c.to_digit(10))
+        .collect::<Option<_>>()
+        .ok_or(format!("invalid tile: {}", text))?;
+    if digits.len() != 3 {
+        return Err(format!("invalid tile: {}", text));
+    }
+    rules.tile(digits[0], digits[1], digits[2])
+}
+
+pub fn format_field(field: &Field) -> String {
+    format!("C{}R{}", field.column as i32, field.row as i32)
+}
+
+// a field of the classic board
+pub fn parse_field(text: &str) -> Result<Field, String> {
+    parse_field_with(text, &RuleSet::default())
+}
+
+pub fn parse_field_with(text: &str, rules: &RuleSet) -> Result<Field, String> {
+    let invalid = || format!("invalid field: {}", text);
+    let upper = text.to_ascii_uppercase();
+    let (column, row) = upper
+        .strip_prefix('C')
+        .and_then(|rest| rest.split_once('R'))
+        .ok_or_else(invalid)?;
+    // no column or row of the board is longer than the middle column
+    let coordinate = |text: &str| {
+        text.parse::<i32>()
+            .ok()
+            .filter(|n| *n <= rules.num_columns())
+            .and_then(|n| Coordinate::from_int(n).ok())
+            .ok_or_else(invalid)
+    };
+    rules.field(coordinate(column)?, coordinate(row)?)
+}
+
+struct Parser<'a> {
+    line: usize,
+    text: &'a str,
+    // byte offset in the current line
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, position: usize, message: String) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.text[..position].chars().count() + 1,
+            message,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.position == self.text.len()
+    }
+
+    // next whitespace separated word and its position
+    fn word(&mut self) -> (usize, &'a str) {
+        self.skip_whitespace();
+        let start = self.position;
+        let rest = &self.text[start..];
+        let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        self.position += len;
+        (start, &rest[..len])
+    }
+
+    // `{ ... }` at the current position, if any
+    fn comment(&mut self) -> Result<Option<String>, ParseError> {
+        self.skip_whitespace();
+        if !self.text[self.position..].starts_with('{') {
+            return Ok(None);
+        }
+        let start = self.position;
+        let mut escaped = false;
+        for (end, c) in self.text[start..].char_indices() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '}' => {
+                    self.position = start + end + 1;
+                    // the closing brace is not escaped, so neither is the end
+                    let comment = unescape(&self.text[start + 1..start + end]).unwrap_or_default();
+                    return Ok(Some(comment.trim().to_string()));
+                }
+                _ => {}
+            }
+        }
+        Err(self.error(start, "unterminated comment".to_string()))
+    }
+
+    fn tag(&mut self) -> Result<(String, String), ParseError> {
+        let start = self.position;
+        let inner = self.text[start..]
+            .trim_end()
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+            .ok_or_else(|| self.error(start, "tag must look like [Name \"value\"]".to_string()))?;
+        let (name, value) = inner
+            .trim()
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| self.error(start + 1, "tag without value".to_string()))?;
+        check_tag_name(name).map_err(|e| self.error(start + 1, e))?;
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .and_then(unescape)
+            .ok_or_else(|| {
+                let position = start + 1 + inner.find(value).unwrap_or(0);
+                self.error(position, "tag value must be quoted".to_string())
+            })?;
+        self.position = self.text.len();
+        Ok((name.to_string(), value))
+    }
+}
+
+impl GameRecord {
+    pub fn new() -> GameRecord {
+        GameRecord::default()
+    }
+
+    pub fn from_game(game: &Game) -> GameRecord {
+        let mut record = GameRecord {
+            moves: game
+                .moves()
+                .iter()
+                .map(|&(tile, field)| Move {
+                    tile,
+                    field,
+                    comment: None,
+                })
+                .collect(),
+            score: if game.finished() {
+                Some(game.board.score())
+            } else {
+                None
+            },
+            ..GameRecord::default()
+        };
+        record
+            .tags
+            .push(("Seed".to_string(), game.seed().to_string()));
+        if !game.rules().is_classic() {
+            record
+                .tags
+                .push(("Ruleset".to_string(), game.rules().to_string()));
+        }
+        record
+    }
+
+    // classic without a Ruleset tag
+    pub fn rules(&self) -> Result<RuleSet, String> {
+        match self.tag("Ruleset") {
+            Some(rules) => rules.parse(),
+            None => Ok(RuleSet::default()),
+        }
+    }
+
+    pub fn seed(&self) -> Result<Option<u64>, String> {
+        self.tag("Seed")
+            .map(|seed| seed.parse().map_err(|_| format!("invalid seed: {}", seed)))
+            .transpose()
+    }
+
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn set_tag(&mut self, name: &str, value: &str) -> Result<(), String> {
+        check_tag_name(name)?;
+        match self
+            .tags
+            .iter_mut()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+        {
+            Some(tag) => tag.1 = value.to_string(),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+        Ok(())
+    }
+
+    pub fn parse(text: &str) -> Result<GameRecord, ParseError> {
+        let mut record = GameRecord::new();
+        let mut score_error = None;
+        for (index, text) in text.lines().enumerate() {
+            let mut parser = Parser {
+                line: index + 1,
+                text,
+                position: 0,
+            };
+            if parser.at_end() {
+                continue;
+            }
+            if record.score.is_some() {
+                return Err(parser.error(parser.position, "text after the score".to_string()));
+            }
+
+            if text[parser.position..].starts_with('[') {
+                if !record.moves.is_empty() {
+                    return Err(parser.error(
+                        parser.position,
+                        "tags must come before the moves".to_string(),
+                    ));
+                }
+                let tag = parser.tag()?;
+                record.tags.push(tag);
+                continue;
+            }
+
+            if let Some(comment) = parser.comment()? {
+                let target = match record.moves.last_mut() {
+                    Some(last) => &mut last.comment,
+                    None => &mut record.comment,
+                };
+                *target = Some(match target.take() {
+                    Some(previous) => format!("{} {}", previous, comment),
+                    None => comment,
+                });
+                if !parser.at_end() {
+                    return Err(parser.error(
+                        parser.position,
+                        "a comment must be on its own line or follow a move".to_string(),
+                    ));
+                }
+                continue;
+            }
+
+            let (start, word) = parser.word();
+            if word.eq_ignore_ascii_case("score") {
+                let (start, score) = parser.word();
+                if score.is_empty() {
+                    return Err(parser.error(start, "missing score".to_string()));
+                }
+                let score = score
+                    .parse::<u32>()
+                    .map_err(|_| parser.error(start, format!("invalid score: {}", score)))?;
+                // reported after the check for text after the score
+                score_error = match record.to_board() {
+                    Ok(board) if board.score() == score => None,
+                    Ok(board) => Some(parser.error(
+                        start,
+                        format!(
+                            "score {} does not match the moves: {}",
+                            score,
+                            board.score()
+                        ),
+                    )),
+                    Err(e) => Some(parser.error(start, e)),
+                };
+                record.score = Some(score);
+            } else {
+                let rules = record.rules().map_err(|e| parser.error(start, e))?;
+                let tile = parse_tile_with(word, &rules).map_err(|e| parser.error(start, e))?;
+                let (start, word) = parser.word();
+                if word.is_empty() {
+                    return Err(parser.error(start, "missing field after the tile".to_string()));
+                }
+                let field = parse_field_with(word, &rules).map_err(|e| parser.error(start, e))?;
+                let comment = parser.comment()?;
+                record.moves.push(Move {
+                    tile,
+                    field,
+                    comment,
+                });
+            }
+            if !parser.at_end() {
+                let (start, word) = parser.word();
+                return Err(parser.error(start, format!("unexpected text: {}", word)));
+            }
+        }
+        match score_error {
+            Some(error) => Err(error),
+            None => Ok(record),
+        }
+    }
+
+    pub fn to_board(&self) -> Result<Board, String> {
+        let mut board = Board::with_rules(self.rules()?);
+        for (i, m) in self.moves.iter().enumerate() {
+            board.place_tile(m.field, m.tile).map_err(|_| {
+                format!(
+                    "move {}: cannot place {} at {}",
+                    i + 1,
+                    format_tile(&m.tile),
+                    format_field(&m.field)
+                )
+            })?;
+        }
+        Ok(board)
+    }
+
+    // with the seed of the Seed tag, a random one without it
+    #[cfg(feature = "entropy")]
+    pub fn to_game(&self) -> Result<Game, String> {
+        self.to_game_with_seed(self.seed()?.unwrap_or_else(rand::random))
+    }
+
+    // the seed decides the tiles drawn after the moves of the record
+    pub fn to_game_with_seed(&self, seed: u64) -> Result<Game, String> {
+        let mut game = Game::with_rules(self.rules()?, seed);
+        for (i, m) in self.moves.iter().enumerate() {
+            let error = || {
+                format!(
+                    "move {}: cannot place {} at {}",
+                    i + 1,
+                    format_tile(&m.tile),
+                    format_field(&m.field)
+                )
+            };
+            game.set_current_tile(m.tile).map_err(|_| error())?;
+            game.place_tile(m.field).map_err(|_| error())?;
+        }
+        Ok(game)
+    }
+}
+
+impl fmt::Display for GameRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, value) in &self.tags {
+            // the parser could not read the name back, see `set_tag`
+            if check_tag_name(name).is_err() {
+                return Err(fmt::Error);
+            }
+            writeln!(f, "[{} \"{}\"]", name, escape(value, '"'))?;
+        }
+        if !self.tags.is_empty() {
+            writeln!(f)?;
+        }
+        if let Some(comment) = &self.comment {
+            writeln!(f, "{{ {} }}", escape(comment, '}'))?;
+        }
+        for m in &self.moves {
+            write!(f, "{} {}", format_tile(&m.tile), format_field(&m.field))?;
+            if let Some(comment) = &m.comment {
+                write!(f, " {{ {} }}", escape(comment, '}'))?;
+            }
+            writeln!(f)?;
+        }
+        if let Some(score) = self.score {
+            writeln!(f, "score {}", score)?;
+        }
+        Ok(())
+    }
+}
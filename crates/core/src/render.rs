@@ -0,0 +1,365 @@
+use crate::ascii::ascii_position;
+use crate::color::{self, Emphasis};
+use crate::rules::{RuleSet, CLASSIC_RADIUS};
+use crate::tile::{Direction, Tile};
+use crate::{Board, Coordinate, Field, LineScore};
+use core::fmt;
+use std::collections::{BTreeSet, HashSet};
+
+// Text drawings of a board, built from the field geometry.
+//
+// Every style can highlight fields, e.g. the field the next tile would go on
+// or the fields of one line, and can color the numbers with ANSI escapes.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+    // the hexagon drawing of `impl Display for Board`
+    #[default]
+    Large,
+    // one line per half row, fits into 80x24
+    Compact,
+    // boxes drawn with Unicode box-drawing characters
+    Unicode,
+    // all tiles and the score in a single line
+    Summary,
+}
+
+// frame of the large drawing of the classic board, as before rule sets with
+// its trailing blanks, other radii only get the outlines of `large`
+const LARGE_FRAME: &str = r"
+                                _______
+                               /       \     
+                       _______/         \_______
+                      /       \         /       \     
+              _______/         \_______/         \_______
+             /       \         /       \         /       \
+            /         \_______/         \_______/         \
+            \         /       \         /       \         /
+             \_______/         \_______/         \_______/   
+             /       \         /       \         /       \
+            /         \_______/         \_______/         \
+            \         /       \         /       \         /
+             \_______/         \_______/         \_______/   
+             /       \         /       \         /       \
+            /         \_______/         \_______/         \
+            \         /       \         /       \         /
+             \_______/         \_______/         \_______/   
+                     \         /       \         /
+                      \_______/         \_______/   
+                              \         /
+                               \_______/";
+
+// a number on a field and the escape sequence it is colored with
+type Number = (String, Option<String>);
+
+struct Canvas {
+    // every character with its escape sequence
+    lines: Vec<Vec<(char, Option<String>)>>,
+}
+
+impl Canvas {
+    fn new(text: &str) -> Canvas {
+        Canvas {
+            lines: text
+                .split('\n')
+                .map(|line| line.chars().map(|c| (c, None)).collect())
+                .collect(),
+        }
+    }
+
+    fn put_styled(&mut self, line: usize, column: usize, text: &str, style: &Option<String>) {
+        if self.lines.len() <= line {
+            self.lines.resize(line + 1, Vec::new());
+        }
+        let line = &mut self.lines[line];
+        for (i, c) in text.chars().enumerate() {
+            if line.len() <= column + i {
+                line.resize(column + i + 1, (' ', None));
+            }
+            line[column + i] = (c, style.clone());
+        }
+    }
+
+    fn put(&mut self, line: usize, column: usize, text: &str) {
+        self.put_styled(line, column, text, &None);
+    }
+
+    fn put_number(&mut self, line: usize, column: usize, number: &Number) {
+        self.put_styled(line, column, &number.0, &number.1);
+    }
+}
+
+impl fmt::Display for Canvas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines: Vec<String> = self
+            .lines
+            .iter()
+            .map(|l| {
+                l.iter()
+                    .map(|(c, style)| match style {
+                        Some(style) => format!("{}{}{}", style, c, color::RESET),
+                        None => c.to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+pub struct BoardView<'a> {
+    board: &'a Board,
+    style: Style,
+    highlighted: HashSet<Field>,
+    colored: bool,
+    labels: bool,
+    lines: Vec<LineScore>,
+}
+
+impl Board {
+    pub fn view(&self, style: Style) -> BoardView<'_> {
+        BoardView {
+            board: self,
+            style,
+            highlighted: HashSet::new(),
+            colored: false,
+            labels: false,
+            lines: self.line_scores(),
+        }
+    }
+}
+
+// (line, column) of the top number of a field in the large style of the
+// classic board
+pub fn large_position(field: &Field) -> (usize, usize) {
+    large_position_with(field, &RuleSet::default())
+}
+
+pub fn large_position_with(field: &Field, rules: &RuleSet) -> (usize, usize) {
+    ascii_position(field, rules.radius())
+}
+
+// field whose hexagon covers a position of the large style, e.g. for mouse
+// clicks
+pub fn large_field_at(line: usize, column: usize) -> Option<Field> {
+    large_field_at_with(line, column, &RuleSet::default())
+}
+
+pub fn large_field_at_with(line: usize, column: usize, rules: &RuleSet) -> Option<Field> {
+    rules.fields().into_iter().find(|field| {
+        let (top, center) = ascii_position(field, rules.radius());
+        (top - 1..=top + 2).contains(&line) && column.abs_diff(center) <= 3
+    })
+}
+
+impl<'a> BoardView<'a> {
+    pub fn highlight_field(mut self, field: Field) -> Self {
+        self.highlighted.insert(field);
+        self
+    }
+
+    pub fn highlight_line(mut self, fields: &[Field]) -> Self {
+        self.highlighted.extend(fields.iter().copied());
+        self
+    }
+
+    // see `color::color_enabled` to decide whether to color
+    pub fn colored(mut self, colored: bool) -> Self {
+        self.colored = colored;
+        self
+    }
+
+    // column labels, and the id and coordinates of every empty field (see
+    // `Field::id`), the drawing still reads back with `Board::from_ascii`
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    fn fields(&self) -> BTreeSet<Field> {
+        self.board.fields()
+    }
+
+    fn radius(&self) -> usize {
+        self.board.rules().radius() as usize
+    }
+
+    // of the top number in the large style
+    fn position(&self, field: &Field) -> (usize, usize) {
+        ascii_position(field, self.board.rules().radius())
+    }
+
+    // (half row, column) from the top left, a field is two half rows high
+    // and each column starts half a field below its left neighbour
+    fn grid_position(&self, field: &Field) -> (usize, usize) {
+        let hex = self.board.rules().hex(field);
+        let radius = self.radius() as i32;
+        (
+            (2 * hex.r + hex.q + 2 * radius) as usize,
+            (hex.q + radius) as usize,
+        )
+    }
+
+    fn tile(&self, field: &Field) -> Option<Tile> {
+        self.board.tiles.get(field).and_then(|tile| *tile)
+    }
+
+    fn emphasis(&self, field: &Field, direction: &Direction) -> Emphasis {
+        match self
+            .lines
+            .iter()
+            .find(|line| line.direction == *direction && line.fields.contains(field))
+        {
+            Some(line) if line.score > 0 => Emphasis::Bold,
+            Some(line) if line.broken => Emphasis::Dim,
+            _ => Emphasis::Normal,
+        }
+    }
+
+    // top, left and right number, blanks or the id on an empty field
+    fn numbers(&self, field: &Field) -> [Number; 3] {
+        let Some(tile) = self.tile(field) else {
+            let top = match field.id_with(self.board.rules()) {
+                Some(id) if self.labels => id.to_string(),
+                _ => " ".to_string(),
+            };
+            return [
+                (top, None),
+                (" ".to_string(), None),
+                (" ".to_string(), None),
+            ];
+        };
+        [Direction::Top, Direction::Left, Direction::Right].map(|direction| {
+            let number = tile.number(&direction);
+            let style = self
+                .colored
+                .then(|| color::escape(number, self.emphasis(field, &direction)));
+            (number.to_string(), style)
+        })
+    }
+
+    fn large(&self) -> Canvas {
+        // the outline of every hexagon, neighbours share their edges
+        let frame = if self.radius() == CLASSIC_RADIUS as usize {
+            LARGE_FRAME
+        } else {
+            ""
+        };
+        let mut canvas = Canvas::new(frame);
+        for field in self.fields() {
+            let (line, column) = self.position(&field);
+            canvas.put(line - 2, column - 3, "_______");
+            canvas.put(line - 1, column - 4, "/");
+            canvas.put(line - 1, column + 4, "\\");
+            canvas.put(line, column - 5, "/");
+            canvas.put(line, column + 5, "\\");
+            canvas.put(line + 1, column - 5, "\\");
+            canvas.put(line + 1, column + 5, "/");
+            canvas.put(line + 2, column - 4, "\\_______/");
+        }
+        for field in self.fields() {
+            let (line, column) = self.position(&field);
+            let [top, left, right] = self.numbers(&field);
+            canvas.put_number(line, column, &top);
+            canvas.put_number(line + 1, column - 2, &left);
+            canvas.put_number(line + 1, column + 2, &right);
+            if self.highlighted.contains(&field) {
+                canvas.put(line - 1, column - 3, "*******");
+            }
+            if self.labels && self.tile(&field).is_none() {
+                let coordinates = format!("{},{}", field.column as i32, field.row as i32);
+                canvas.put(line + 1, column - 1, &coordinates);
+            }
+        }
+        if self.labels {
+            // column labels above the columns, rows count within a column and
+            // are only in the coordinates of the empty fields
+            for field in self.fields() {
+                let (line, column) = self.position(&field);
+                if field.row == Coordinate::C1 {
+                    canvas.put(line - 3, column - 1, &format!("C{}", field.column as i32));
+                }
+            }
+        }
+        canvas
+    }
+
+    fn compact(&self) -> Canvas {
+        let mut canvas = Canvas::new("");
+        for field in self.fields() {
+            let (line, column) = self.grid_position(&field);
+            let x = 6 * column;
+            let [top, left, right] = self.numbers(&field);
+            let (open, close) = if self.highlighted.contains(&field) {
+                ("[", "]")
+            } else {
+                ("(", ")")
+            };
+            canvas.put(line, x, open);
+            canvas.put_number(line, x + 1, &top);
+            canvas.put_number(line, x + 2, &left);
+            canvas.put_number(line, x + 3, &right);
+            canvas.put(line, x + 4, close);
+        }
+        canvas
+    }
+
+    fn unicode(&self) -> Canvas {
+        let mut canvas = Canvas::new("");
+        for field in self.fields() {
+            let (half_row, column) = self.grid_position(&field);
+            let (line, x) = (2 * half_row, 8 * column);
+            let [top, left, right] = self.numbers(&field);
+            let [tl, h, tr, v, bl, br] = if self.highlighted.contains(&field) {
+                ["╔", "═", "╗", "║", "╚", "╝"]
+            } else {
+                ["╭", "─", "╮", "│", "╰", "╯"]
+            };
+            canvas.put(line, x, &format!("{}{}{}", tl, h.repeat(5), tr));
+            canvas.put(line + 1, x, &format!("{}     {}", v, v));
+            canvas.put_number(line + 1, x + 3, &top);
+            canvas.put(line + 2, x, &format!("{}     {}", v, v));
+            canvas.put_number(line + 2, x + 2, &left);
+            canvas.put_number(line + 2, x + 4, &right);
+            canvas.put(line + 3, x, &format!("{}{}{}", bl, h.repeat(5), br));
+        }
+        canvas
+    }
+
+    fn summary(&self) -> String {
+        let mut columns: Vec<Vec<String>> = vec![Vec::new(); 2 * self.radius() + 1];
+        for field in self.fields() {
+            let text = match self.tile(&field) {
+                Some(_) => self
+                    .numbers(&field)
+                    .iter()
+                    .map(|(number, style)| match style {
+                        Some(style) => format!("{}{}{}", style, number, color::RESET),
+                        None => number.clone(),
+                    })
+                    .collect(),
+                None => "---".to_string(),
+            };
+            let text = if self.highlighted.contains(&field) {
+                format!("[{}]", text)
+            } else {
+                text
+            };
+            columns[field.column as usize - 1].push(text);
+        }
+        let columns: Vec<String> = columns.iter().map(|c| c.join(" ")).collect();
+        let score: u32 = self.lines.iter().map(|l| l.score).sum();
+        format!("{} | score {}", columns.join(" | "), score)
+    }
+}
+
+impl fmt::Display for BoardView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.style {
+            Style::Large => write!(f, "{}", self.large()),
+            Style::Compact => write!(f, "{}", self.compact()),
+            Style::Unicode => write!(f, "{}", self.unicode()),
+            Style::Summary => write!(f, "{}", self.summary()),
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use crate::hex::{self, Hex};
+use crate::tile::{Direction, Tile};
+use crate::{Coordinate, Field};
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+// The numbers and the board of a game.
+//
+// Every combination of one number per direction is a tile. The classic game
+// has 1 5 9 from top to bottom, 2 6 7 from down left to up right and 3 4 8
+// from down right to up left, 27 tiles in all. Variants pick other numbers,
+// they must be digits from 1 to 9 (the record notation and the drawings of
+// the board have one character per number) and differ within a direction.
+//
+// The board is a hexagon of fields around the center field, the radius is
+// the number of rings: 1 gives 7 fields, the classic 2 gives 19 and 3 gives
+// 37 (see `hex` for the geometry). Fields are counted in columns from left to
+// right and rows from top to bottom, the middle column is the longest with
+// 2 * radius + 1 fields. A game needs at least as many tiles as the board has
+// fields, the rest stay in the bag.
+//
+// The text form lists the numbers per direction like tiles and the radius if
+// it is not the classic one, "159 267 348" or "1359 2467 3478 r3".
+
+pub const CLASSIC_RADIUS: u32 = 2;
+pub const MAX_RADIUS: u32 = 3;
+// the searches of the AI keep sets of tiles in bit masks
+pub const MAX_TILES: usize = 128;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuleSet {
+    // bit n is set for the number n
+    top: u16,
+    left: u16,
+    right: u16,
+    radius: u32,
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        RuleSet::CLASSIC
+    }
+}
+
+fn direction_name(direction: &Direction) -> &'static str {
+    match direction {
+        Direction::Top => "top",
+        Direction::Left => "left",
+        Direction::Right => "right",
+    }
+}
+
+fn check_numbers(direction: &Direction, numbers: &[u32]) -> Result<u16, String> {
+    let name = direction_name(direction);
+    if numbers.is_empty() {
+        return Err(format!("no {} numbers", name));
+    }
+    let mut mask = 0u16;
+    for &number in numbers {
+        if !(1..=9).contains(&number) {
+            return Err(format!("invalid {} number: {}", name, number));
+        }
+        if mask & 1 << number != 0 {
+            return Err(format!("{} numbers must differ", name));
+        }
+        mask |= 1 << number;
+    }
+    Ok(mask)
+}
+
+impl RuleSet {
+    pub const CLASSIC: RuleSet = RuleSet {
+        top: 1 << 1 | 1 << 5 | 1 << 9,
+        left: 1 << 2 | 1 << 6 | 1 << 7,
+        right: 1 << 3 | 1 << 4 | 1 << 8,
+        radius: CLASSIC_RADIUS,
+    };
+
+    // the numbers may come in any order
+    pub fn new(top: [u32; 3], left: [u32; 3], right: [u32; 3]) -> Result<RuleSet, String> {
+        RuleSet::with_radius(CLASSIC_RADIUS, &top, &left, &right)
+    }
+
+    // also more or fewer numbers per direction, as long as the tiles cover
+    // the board
+    pub fn with_radius(
+        radius: u32,
+        top: &[u32],
+        left: &[u32],
+        right: &[u32],
+    ) -> Result<RuleSet, String> {
+        if !(1..=MAX_RADIUS).contains(&radius) {
+            return Err(format!("invalid radius: {}", radius));
+        }
+        let rules = RuleSet {
+            top: check_numbers(&Direction::Top, top)?,
+            left: check_numbers(&Direction::Left, left)?,
+            right: check_numbers(&Direction::Right, right)?,
+            radius,
+        };
+        let tiles = top.len() * left.len() * right.len();
+        if tiles < rules.num_fields() {
+            return Err(format!(
+                "{} tiles are too few for {} fields",
+                tiles,
+                rules.num_fields()
+            ));
+        }
+        if tiles > MAX_TILES {
+            return Err(format!("{} tiles are more than {}", tiles, MAX_TILES));
+        }
+        Ok(rules)
+    }
+
+    pub fn is_classic(&self) -> bool {
+        *self == RuleSet::CLASSIC
+    }
+
+    // ascending
+    pub fn numbers(&self, direction: &Direction) -> Vec<u32> {
+        let mask = match direction {
+            Direction::Top => self.top,
+            Direction::Left => self.left,
+            Direction::Right => self.right,
+        };
+        (1..=9).filter(|n| mask & 1 << n != 0).collect()
+    }
+
+    pub fn max_number(&self, direction: &Direction) -> u32 {
+        *self.numbers(direction).last().unwrap()
+    }
+
+    pub fn check_number(&self, direction: &Direction, number: u32) -> Result<u32, String> {
+        if self.numbers(direction).contains(&number) {
+            Ok(number)
+        } else {
+            Err(format!(
+                "invalid {} number: {}",
+                direction_name(direction),
+                number
+            ))
+        }
+    }
+
+    pub fn tile(&self, top: u32, left: u32, right: u32) -> Result<Tile, String> {
+        Ok(Tile {
+            top: self.check_number(&Direction::Top, top)?,
+            left: self.check_number(&Direction::Left, left)?,
+            right: self.check_number(&Direction::Right, right)?,
+        })
+    }
+
+    pub fn contains(&self, tile: &Tile) -> bool {
+        self.tile(tile.top, tile.left, tile.right).is_ok()
+    }
+
+    pub fn tiles(&self) -> BTreeSet<Tile> {
+        let mut tiles = BTreeSet::new();
+        for top in self.numbers(&Direction::Top) {
+            for left in self.numbers(&Direction::Left) {
+                for right in self.numbers(&Direction::Right) {
+                    tiles.insert(Tile { top, left, right });
+                }
+            }
+        }
+        tiles
+    }
+
+    pub fn radius(&self) -> u32 {
+        self.radius
+    }
+
+    pub fn num_fields(&self) -> usize {
+        let radius = self.radius as usize;
+        3 * radius * radius + 3 * radius + 1
+    }
+
+    pub fn num_columns(&self) -> i32 {
+        2 * self.radius as i32 + 1
+    }
+
+    // number of fields in a column, 0 outside the board
+    pub fn column_len(&self, column: i32) -> i32 {
+        let q = column - self.radius as i32 - 1;
+        hex::line(&Direction::Top, q, self.radius).count() as i32
+    }
+
+    pub fn field(&self, column: Coordinate, row: Coordinate) -> Result<Field, String> {
+        let field = Field { column, row };
+        if Hex::from_field(&field, self.radius).length() > self.radius as i32 {
+            return Err(format!(
+                "invalid coordinates: {}, {}",
+                column as i32, row as i32
+            ));
+        }
+        Ok(field)
+    }
+
+    pub fn hex(&self, field: &Field) -> Hex {
+        Hex::from_field(field, self.radius)
+    }
+
+    pub fn fields(&self) -> BTreeSet<Field> {
+        hex::hexes(self.radius)
+            .filter_map(|hex| hex.to_field(self.radius))
+            .collect()
+    }
+
+    // fields of the scoring lines in one direction, see `Hex::line`
+    pub fn lines(&self, direction: &Direction) -> Vec<Vec<Field>> {
+        let radius = self.radius as i32;
+        (-radius..=radius)
+            .map(|line| {
+                hex::line(direction, line, self.radius)
+                    .filter_map(|hex| hex.to_field(self.radius))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for RuleSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, direction) in [Direction::Top, Direction::Left, Direction::Right]
+            .iter()
+            .enumerate()
+        {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            for number in self.numbers(direction) {
+                write!(f, "{}", number)?;
+            }
+        }
+        if self.radius != CLASSIC_RADIUS {
+            write!(f, " r{}", self.radius)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for RuleSet {
+    type Err = String;
+
+    // "159 267 348", "159 267 348 r1" or "classic"
+    fn from_str(text: &str) -> Result<RuleSet, String> {
+        if text.trim().eq_ignore_ascii_case("classic") {
+            return Ok(RuleSet::CLASSIC);
+        }
+        let invalid = || format!("invalid rule set: {}", text);
+        let mut groups: [Vec<u32>; 3] = Default::default();
+        let mut words = text.split_whitespace();
+        for group in groups.iter_mut() {
+            *group = words
+                .next()
+                .ok_or_else(invalid)?
+                .chars()
+                .map(|c| c.to_digit(10))
+                .collect::<Option<_>>()
+                .ok_or_else(invalid)?;
+        }
+        let radius = match words.next() {
+            Some(word) => word
+                .strip_prefix(['r', 'R'])
+                .and_then(|radius| radius.parse().ok())
+                .ok_or_else(invalid)?,
+            None => CLASSIC_RADIUS,
+        };
+        if words.next().is_some() {
+            return Err(invalid());
+        }
+        RuleSet::with_radius(radius, &groups[0], &groups[1], &groups[2])
+    }
+}
@@ -0,0 +1,162 @@
+use crate::record::{format_field, format_tile, parse_field_with, parse_tile_with};
+use crate::rules::RuleSet;
+use crate::tile::Tile;
+use crate::{Board, Game, TileReservoir};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::collections::BTreeSet;
+
+// Save files of games in progress:
+//
+//     takeiteasy-save 1
+//     rules 259 167 348
+//     seed 42
+//     rng 96
+//     current 973
+//     remaining 123 124 128 ...
+//     move 528 C3R3
+//     move 164 C1R1
+//
+// `rng` is the word position of the random number generator, so a loaded game
+// draws the same tiles as the saved one would have. `rules` is only written for
+// variants (see `RuleSet`) and comes before the tiles. The first line carries
+// the version of the format.
+
+pub const SAVE_VERSION: u32 = 1;
+const MAGIC: &str = "takeiteasy-save";
+
+impl Game {
+    pub fn save(&self) -> String {
+        let mut text = format!("{} {}\n", MAGIC, SAVE_VERSION);
+        if !self.rules().is_classic() {
+            text += &format!("rules {}\n", self.rules());
+        }
+        text += &format!("seed {}\n", self.seed);
+        text += &format!("rng {}\n", self.rng.get_word_pos());
+        if let Some(tile) = &self.current_tile {
+            text += &format!("current {}\n", format_tile(tile));
+        }
+        let mut remaining: Vec<Tile> = self
+            .tile_reservoir
+            .remaining_tiles
+            .iter()
+            .copied()
+            .collect();
+        remaining.sort();
+        let remaining: Vec<String> = remaining.iter().map(format_tile).collect();
+        text += &format!("remaining {}\n", remaining.join(" "));
+        for (tile, field) in &self.moves {
+            text += &format!("move {} {}\n", format_tile(tile), format_field(field));
+        }
+        text
+    }
+
+    pub fn load(text: &str) -> Result<Game, String> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty());
+        let version = match lines
+            .next()
+            .map(|(_, l)| l.split_whitespace().collect::<Vec<_>>())
+        {
+            Some(words) if words.len() == 2 && words[0] == MAGIC => words[1]
+                .parse::<u32>()
+                .map_err(|_| format!("invalid version: {}", words[1]))?,
+            _ => return Err("not a save file".to_string()),
+        };
+        if version != SAVE_VERSION {
+            return Err(format!("unsupported version: {}", version));
+        }
+
+        let mut rules = RuleSet::default();
+        let mut seed = None;
+        let mut word_pos = None;
+        let mut current_tile = None;
+        let mut remaining: Option<Vec<Tile>> = None;
+        let mut board = Board::new();
+        let mut moves = Vec::new();
+        for (index, line) in lines {
+            let error = |message: String| format!("line {}: {}", index + 1, message);
+            let (key, value) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+            let value = value.trim();
+            match key {
+                "rules" => {
+                    if current_tile.is_some() || remaining.is_some() || !moves.is_empty() {
+                        return Err(error("rules must come before the tiles".to_string()));
+                    }
+                    rules = value.parse().map_err(error)?;
+                    board = Board::with_rules(rules);
+                }
+                "seed" => {
+                    seed = Some(
+                        value
+                            .parse::<u64>()
+                            .map_err(|_| error(format!("invalid seed: {}", value)))?,
+                    )
+                }
+                "rng" => {
+                    word_pos = Some(
+                        value
+                            .parse::<u128>()
+                            .map_err(|_| error(format!("invalid rng state: {}", value)))?,
+                    )
+                }
+                "current" => current_tile = Some(parse_tile_with(value, &rules).map_err(error)?),
+                "remaining" => {
+                    remaining = Some(
+                        value
+                            .split_whitespace()
+                            .map(|tile| parse_tile_with(tile, &rules))
+                            .collect::<Result<_, _>>()
+                            .map_err(error)?,
+                    )
+                }
+                "move" => {
+                    let (tile, field) = value
+                        .split_once(' ')
+                        .ok_or_else(|| error(format!("invalid move: {}", value)))?;
+                    let tile = parse_tile_with(tile, &rules).map_err(error)?;
+                    let field = parse_field_with(field.trim(), &rules).map_err(error)?;
+                    board.place_tile(field, tile).map_err(|_| {
+                        error(format!(
+                            "cannot place {} at {}",
+                            format_tile(&tile),
+                            format_field(&field)
+                        ))
+                    })?;
+                    moves.push((tile, field));
+                }
+                _ => return Err(error(format!("unknown entry: {}", key))),
+            }
+        }
+        let seed = seed.ok_or("missing seed")?;
+        let word_pos = word_pos.ok_or("missing rng state")?;
+        let remaining = remaining.ok_or("missing remaining tiles")?;
+
+        // placed, current and remaining tiles together are the full set
+        let mut tiles: Vec<Tile> = moves.iter().map(|(tile, _)| *tile).collect();
+        tiles.extend(current_tile);
+        tiles.extend(&remaining);
+        let set: BTreeSet<Tile> = tiles.iter().copied().collect();
+        if set.len() != tiles.len() || set != rules.tiles() {
+            return Err("tiles of the game are incomplete".to_string());
+        }
+        if current_tile.is_none() && !remaining.is_empty() {
+            return Err("no current tile but tiles remaining".to_string());
+        }
+
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        rng.set_word_pos(word_pos);
+        Ok(Game {
+            board,
+            tile_reservoir: TileReservoir {
+                remaining_tiles: remaining.into_iter().collect(),
+            },
+            current_tile,
+            moves,
+            seed,
+            rng,
+        })
+    }
+}
@@ -0,0 +1,349 @@
+use crate::rules::{RuleSet, CLASSIC_RADIUS};
+use crate::tile::{Direction, Tile};
+use crate::{Board, Coordinate, Field, Game, TileReservoir};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+// Serde support behind the `serde` feature.
+//
+// Numbers and coordinates are plain integers, tiles are `[top, left, right]`
+// and fields `{"column": 3, "row": 2}`. Rule sets list their numbers per
+// direction, `{"top": [1, 5, 9], "left": [2, 6, 7], "right": [3, 4, 8]}`,
+// and the radius if it is not the classic one. Boards carry their rule set
+// only for variants. Boards, reservoirs and games list their tiles sorted so
+// the output is stable, games also keep their seed and random number
+// generator so they go on drawing the same tiles. Deserializing goes through the same checks as
+// `Field::new` (`RuleSet::field` for the fields of a board),
+// `RuleSet::with_radius`, `Board::place_tile` and `RuleSet::tile` of the
+// classic game for a tile on its own.
+
+macro_rules! serialize_as_int {
+    ($type:ty, $name:expr) => {
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_i32(*self as i32)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let num = i32::deserialize(deserializer)?;
+                <$type>::from_int(num)
+                    .map_err(|_| de::Error::custom(format!("invalid {}: {}", $name, num)))
+            }
+        }
+    };
+}
+
+serialize_as_int!(Coordinate, "coordinate");
+
+impl Serialize for Tile {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.top, self.left, self.right).serialize(serializer)
+    }
+}
+
+// a tile on its own is one of the classic game, see `AnyTile` for the tiles of
+// boards and games
+impl<'de> Deserialize<'de> for Tile {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (top, left, right): (u32, u32, u32) = Deserialize::deserialize(deserializer)?;
+        RuleSet::default()
+            .tile(top, left, right)
+            .map_err(de::Error::custom)
+    }
+}
+
+// a tile of any rule set, the rule set of the board or game decides
+#[derive(PartialEq, Clone, Copy, Serialize, Deserialize)]
+struct AnyTile(u32, u32, u32);
+
+impl From<Tile> for AnyTile {
+    fn from(tile: Tile) -> Self {
+        AnyTile(tile.top, tile.left, tile.right)
+    }
+}
+
+impl AnyTile {
+    fn tile<E: de::Error>(self) -> Result<Tile, E> {
+        let AnyTile(top, left, right) = self;
+        if let Some(number) = [top, left, right].iter().find(|n| !(1..=9).contains(*n)) {
+            return Err(E::custom(format!("invalid number: {}", number)));
+        }
+        Ok(Tile { top, left, right })
+    }
+}
+
+fn classic_radius() -> u32 {
+    CLASSIC_RADIUS
+}
+
+fn is_classic_radius(radius: &u32) -> bool {
+    *radius == CLASSIC_RADIUS
+}
+
+#[derive(Serialize, Deserialize)]
+struct RuleSetRepr {
+    top: Vec<u32>,
+    left: Vec<u32>,
+    right: Vec<u32>,
+    #[serde(default = "classic_radius", skip_serializing_if = "is_classic_radius")]
+    radius: u32,
+}
+
+impl From<&RuleSet> for RuleSetRepr {
+    fn from(rules: &RuleSet) -> Self {
+        RuleSetRepr {
+            top: rules.numbers(&Direction::Top),
+            left: rules.numbers(&Direction::Left),
+            right: rules.numbers(&Direction::Right),
+            radius: rules.radius(),
+        }
+    }
+}
+
+impl Serialize for RuleSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RuleSetRepr::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RuleSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = RuleSetRepr::deserialize(deserializer)?;
+        RuleSet::with_radius(repr.radius, &repr.top, &repr.left, &repr.right)
+            .map_err(de::Error::custom)
+    }
+}
+
+impl RuleSet {
+    pub fn from_toml(text: &str) -> Result<RuleSet, String> {
+        toml::from_str(text).map_err(|e| e.to_string())
+    }
+
+    pub fn from_json(text: &str) -> Result<RuleSet, String> {
+        serde_json::from_str(text).map_err(|e| e.to_string())
+    }
+
+    // JSON for .json files, TOML otherwise
+    pub fn load(path: &Path) -> Result<RuleSet, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        let rules = match path.extension() {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => RuleSet::from_json(&text),
+            _ => RuleSet::from_toml(&text),
+        };
+        rules.map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
+
+#[derive(PartialEq, Serialize, Deserialize)]
+struct FieldRepr {
+    column: Coordinate,
+    row: Coordinate,
+}
+
+impl From<Field> for FieldRepr {
+    fn from(field: Field) -> Self {
+        FieldRepr {
+            column: field.column,
+            row: field.row,
+        }
+    }
+}
+
+impl Serialize for Field {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        FieldRepr::from(*self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = FieldRepr::deserialize(deserializer)?;
+        Field::new(repr.column, repr.row).map_err(de::Error::custom)
+    }
+}
+
+// the field is checked against the board, see `board_from_placements`
+#[derive(PartialEq, Serialize, Deserialize)]
+struct Placement {
+    field: FieldRepr,
+    tile: AnyTile,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BoardRepr {
+    #[serde(default, skip_serializing_if = "RuleSet::is_classic")]
+    rules: RuleSet,
+    tiles: Vec<Placement>,
+}
+
+fn placements(board: &Board) -> Vec<Placement> {
+    let mut tiles: Vec<Placement> = board
+        .tiles
+        .iter()
+        .filter_map(|(&field, tile)| {
+            tile.map(|tile| Placement {
+                field: field.into(),
+                tile: tile.into(),
+            })
+        })
+        .collect();
+    tiles.sort_by_key(|p| (p.field.column, p.field.row));
+    tiles
+}
+
+fn board_from_placements<E: de::Error>(rules: RuleSet, tiles: &[Placement]) -> Result<Board, E> {
+    let mut board = Board::with_rules(rules);
+    for p in tiles {
+        let field = rules
+            .field(p.field.column, p.field.row)
+            .map_err(E::custom)?;
+        let tile = p.tile.tile()?;
+        board
+            .place_tile(field, tile)
+            .map_err(|_| E::custom(format!("cannot place {} at {}", tile, field)))?;
+    }
+    Ok(board)
+}
+
+impl Serialize for Board {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        BoardRepr {
+            rules: *self.rules(),
+            tiles: placements(self),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Board {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = BoardRepr::deserialize(deserializer)?;
+        board_from_placements(repr.rules, &repr.tiles)
+    }
+}
+
+fn sorted_tiles(tiles: &BTreeSet<Tile>) -> Vec<AnyTile> {
+    tiles.iter().map(|&tile| tile.into()).collect()
+}
+
+fn distinct_tiles<E: de::Error>(tiles: &[AnyTile]) -> Result<BTreeSet<Tile>, E> {
+    let mut set = BTreeSet::new();
+    for tile in tiles {
+        let tile = tile.tile()?;
+        if !set.insert(tile) {
+            return Err(E::custom(format!("duplicate {}", tile)));
+        }
+    }
+    Ok(set)
+}
+
+#[derive(Serialize, Deserialize)]
+struct TileReservoirRepr {
+    remaining_tiles: Vec<AnyTile>,
+}
+
+impl Serialize for TileReservoir {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TileReservoirRepr {
+            remaining_tiles: sorted_tiles(&self.remaining_tiles),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for TileReservoir {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = TileReservoirRepr::deserialize(deserializer)?;
+        Ok(TileReservoir {
+            remaining_tiles: distinct_tiles(&repr.remaining_tiles)?,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct GameRepr {
+    board: Board,
+    moves: Vec<Placement>,
+    current_tile: Option<AnyTile>,
+    remaining_tiles: Vec<AnyTile>,
+    seed: u64,
+    // the word position of the random number generator, as in save files
+    rng: u128,
+}
+
+impl Serialize for Game {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GameRepr {
+            board: self.board.clone(),
+            moves: self
+                .moves
+                .iter()
+                .map(|&(tile, field)| Placement {
+                    field: field.into(),
+                    tile: tile.into(),
+                })
+                .collect(),
+            current_tile: self.current_tile.map(AnyTile::from),
+            remaining_tiles: sorted_tiles(&self.tile_reservoir.remaining_tiles),
+            seed: self.seed,
+            rng: self.rng.get_word_pos(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Game {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = GameRepr::deserialize(deserializer)?;
+        let rules = *repr.board.rules();
+        let board = board_from_placements(rules, &repr.moves)?;
+        if placements(&board) != placements(&repr.board) {
+            return Err(de::Error::custom("board does not match the moves"));
+        }
+
+        // placed, current and remaining tiles together are the full set
+        let mut tiles: Vec<AnyTile> = repr.moves.iter().map(|p| p.tile).collect();
+        tiles.extend(repr.current_tile);
+        tiles.extend(&repr.remaining_tiles);
+        if distinct_tiles(&tiles)? != rules.tiles() {
+            return Err(de::Error::custom("tiles of the game are incomplete"));
+        }
+        if repr.current_tile.is_none() && !repr.remaining_tiles.is_empty() {
+            return Err(de::Error::custom("no current tile but tiles remaining"));
+        }
+
+        Ok(Game {
+            board,
+            tile_reservoir: TileReservoir {
+                remaining_tiles: distinct_tiles(&repr.remaining_tiles)?,
+            },
+            current_tile: repr.current_tile.map(AnyTile::tile).transpose()?,
+            // the fields are checked by `board_from_placements`
+            moves: repr
+                .moves
+                .iter()
+                .map(|p| {
+                    let field = Field {
+                        column: p.field.column,
+                        row: p.field.row,
+                    };
+                    Ok((p.tile.tile()?, field))
+                })
+                .collect::<Result<_, D::Error>>()?,
+            seed: repr.seed,
+            rng: {
+                let mut rng = ChaCha8Rng::seed_from_u64(repr.seed);
+                rng.set_word_pos(repr.rng);
+                rng
+            },
+        })
+    }
+}
@@ -0,0 +1,144 @@
+use crate::rules::RuleSet;
+use crate::tile::Tile;
+use crate::{Field, Game};
+
+// Several players on the same draw. Every player has a game with the same
+// seed and rules, so they all draw the same tiles. A round ends when everyone
+// placed the tile of the round, nobody sees the next tile before.
+
+#[derive(Debug, Clone)]
+pub struct Player {
+    pub name: String,
+    pub game: Game,
+}
+
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub players: Vec<Player>,
+}
+
+impl Session {
+    // a session of the classic game
+    pub fn new(names: &[String], seed: u64) -> Result<Session, String> {
+        Session::with_rules(names, RuleSet::default(), seed)
+    }
+
+    pub fn with_rules(names: &[String], rules: RuleSet, seed: u64) -> Result<Session, String> {
+        if names.is_empty() {
+            return Err("a game needs at least one player".to_string());
+        }
+        let mut players: Vec<Player> = Vec::new();
+        for name in names {
+            if name.trim().is_empty() {
+                return Err("player names must not be empty".to_string());
+            }
+            if players.iter().any(|p| p.name == *name) {
+                return Err(format!("duplicate player: {}", name));
+            }
+            players.push(Player {
+                name: name.clone(),
+                game: Game::with_rules(rules, seed),
+            });
+        }
+        Ok(Session { players })
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.players[0].game.seed()
+    }
+
+    pub fn rules(&self) -> &RuleSet {
+        self.players[0].game.rules()
+    }
+
+    // number of finished rounds
+    pub fn round(&self) -> usize {
+        self.players
+            .iter()
+            .map(|p| p.game.moves().len())
+            .min()
+            .unwrap()
+    }
+
+    pub fn finished(&self) -> bool {
+        self.players.iter().all(|p| p.game.finished())
+    }
+
+    // tile everyone has to place in this round
+    pub fn current_tile(&self) -> Option<Tile> {
+        if self.finished() {
+            return None;
+        }
+        let round = self.round();
+        self.players
+            .iter()
+            .find(|p| p.game.moves().len() == round)
+            .and_then(|p| p.game.current_tile)
+    }
+
+    pub fn player(&self, name: &str) -> Result<&Player, String> {
+        self.players
+            .iter()
+            .find(|p| p.name == name)
+            .ok_or(format!("unknown player: {}", name))
+    }
+
+    // players that still have to place the tile of this round
+    pub fn waiting_for(&self) -> Vec<&str> {
+        let round = self.round();
+        if self.finished() {
+            return Vec::new();
+        }
+        self.players
+            .iter()
+            .filter(|p| p.game.moves().len() == round)
+            .map(|p| p.name.as_str())
+            .collect()
+    }
+
+    pub fn place(&mut self, name: &str, field: Field) -> Result<(), String> {
+        let round = self.round();
+        let finished = self.finished();
+        let player = self
+            .players
+            .iter_mut()
+            .find(|p| p.name == name)
+            .ok_or(format!("unknown player: {}", name))?;
+        if finished || player.game.finished() {
+            return Err("the game is finished".to_string());
+        }
+        if player.game.moves().len() > round {
+            return Err(format!("{} already placed the tile of this round", name));
+        }
+        player.game.check_placement(&field)?;
+        player
+            .game
+            .place_tile(field)
+            .map_err(|_| format!("cannot place on {}", field))
+    }
+
+    // a player leaving the game, the round ends if everyone else placed the
+    // tile of the round
+    pub fn remove(&mut self, name: &str) -> Result<Player, String> {
+        let index = self
+            .players
+            .iter()
+            .position(|p| p.name == name)
+            .ok_or(format!("unknown player: {}", name))?;
+        if self.players.len() == 1 {
+            return Err("a game needs at least one player".to_string());
+        }
+        Ok(self.players.remove(index))
+    }
+
+    // names and scores, best first
+    pub fn standings(&self) -> Vec<(&str, u32)> {
+        let mut standings: Vec<(&str, u32)> = self
+            .players
+            .iter()
+            .map(|p| (p.name.as_str(), p.game.board.score()))
+            .collect();
+        standings.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+        standings
+    }
+}
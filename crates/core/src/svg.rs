@@ -0,0 +1,223 @@
+use crate::hex::Hex;
+use crate::tile::{number_color, Direction};
+use crate::{Board, Field};
+use core::fmt::Write;
+use strum::IntoEnumIterator;
+
+// SVG drawing of a board in the style of the physical game: flat topped
+// hexagons with a colored bar per number. With the `png` feature the drawing
+// can also be rasterized.
+
+const SIZE: f64 = 50.0; // center to corner of a hexagon
+const MARGIN: f64 = 60.0;
+
+#[derive(Debug, Clone)]
+pub struct SvgOptions {
+    pub highlight_lines: bool,
+    pub field_labels: bool,
+    pub score_overlay: bool,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        SvgOptions {
+            highlight_lines: true,
+            field_labels: true,
+            score_overlay: false,
+        }
+    }
+}
+
+fn color(number: u32) -> String {
+    let (r, g, b) = number_color(number);
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+fn center(field: &Field, radius: u32) -> (f64, f64) {
+    let hex = Hex::from_field(field, radius);
+    let (q, r, radius) = (hex.q as f64, hex.r as f64, radius as f64);
+    let height = 3f64.sqrt() * SIZE;
+    (
+        MARGIN + SIZE + 1.5 * SIZE * (q + radius),
+        MARGIN + height / 2.0 + height * (r + q / 2.0 + radius),
+    )
+}
+
+// end points of the bar of a direction, through the middle of two edges
+fn bar(field: &Field, direction: &Direction, radius: u32) -> ((f64, f64), (f64, f64)) {
+    let (x, y) = center(field, radius);
+    let distance = 3f64.sqrt() / 2.0 * SIZE;
+    let angle: f64 = match direction {
+        Direction::Top => 90.0,
+        Direction::Left => 30.0,
+        Direction::Right => 150.0,
+    };
+    let (dx, dy) = (
+        distance * angle.to_radians().cos(),
+        -distance * angle.to_radians().sin(),
+    );
+    ((x - dx, y - dy), (x + dx, y + dy))
+}
+
+fn hexagon_points(field: &Field, radius: u32) -> String {
+    let (x, y) = center(field, radius);
+    (0..6)
+        .map(|i| {
+            let angle = (60.0 * i as f64).to_radians();
+            format!(
+                "{:.1},{:.1}",
+                x + SIZE * angle.cos(),
+                y + SIZE * angle.sin()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+impl Board {
+    pub fn to_svg(&self, options: &SvgOptions) -> String {
+        let radius = self.rules().radius();
+        let fields = self.fields();
+        let width = 2.0 * MARGIN + (3 * radius + 2) as f64 * SIZE;
+        let height = 2.0 * MARGIN
+            + (2 * radius + 1) as f64 * 3f64.sqrt() * SIZE
+            + if options.score_overlay { 40.0 } else { 0.0 };
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{:.0}" height="{:.0}" viewBox="0 0 {:.0} {:.0}" font-family="sans-serif">"#,
+            width, height, width, height
+        );
+        let _ = writeln!(
+            svg,
+            r##"<rect width="100%" height="100%" fill="#30343a"/>"##
+        );
+
+        for field in &fields {
+            let tile = self.tiles.get(field).and_then(|t| *t);
+            let fill = if tile.is_some() { "#f5f0e6" } else { "#4a4f57" };
+            let _ = writeln!(
+                svg,
+                r##"<polygon points="{}" fill="{}" stroke="#1e2126" stroke-width="3"/>"##,
+                hexagon_points(field, radius),
+                fill
+            );
+            let (x, y) = center(field, radius);
+            match tile {
+                Some(tile) => {
+                    // vertical bar last, it lies on top in the physical game
+                    for direction in [Direction::Right, Direction::Left, Direction::Top] {
+                        let number = tile.number(&direction);
+                        let ((x1, y1), (x2, y2)) = bar(field, &direction, radius);
+                        let _ = writeln!(
+                            svg,
+                            r#"<line x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" stroke="{}" stroke-width="{:.1}"/>"#,
+                            x1,
+                            y1,
+                            x2,
+                            y2,
+                            color(number),
+                            SIZE * 0.28
+                        );
+                    }
+                    // top number at the top, left and right number at the bottom
+                    for direction in Direction::iter() {
+                        let (start, end) = bar(field, &direction, radius);
+                        let (ex, ey) = if direction == Direction::Top {
+                            end
+                        } else {
+                            start
+                        };
+                        let (tx, ty) = (x + (ex - x) * 0.62, y + (ey - y) * 0.62);
+                        let _ = writeln!(
+                            svg,
+                            r##"<text x="{:.1}" y="{:.1}" font-size="{:.0}" font-weight="bold" text-anchor="middle" dominant-baseline="central" fill="#1e2126">{}</text>"##,
+                            tx,
+                            ty,
+                            SIZE * 0.34,
+                            tile.number(&direction)
+                        );
+                    }
+                }
+                None if options.field_labels => {
+                    let _ = writeln!(
+                        svg,
+                        r##"<text x="{:.1}" y="{:.1}" font-size="{:.0}" text-anchor="middle" dominant-baseline="central" fill="#c8ccd2">{},{}</text>"##,
+                        x,
+                        y,
+                        SIZE * 0.36,
+                        field.column as i32,
+                        field.row as i32
+                    );
+                }
+                None => {}
+            }
+        }
+
+        let lines = self.line_scores();
+        if options.highlight_lines {
+            for line in lines.iter().filter(|line| line.score > 0) {
+                let points: Vec<String> = line
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let (x, y) = center(field, radius);
+                        format!("{:.1},{:.1}", x, y)
+                    })
+                    .collect();
+                let number = line.score / line.fields.len() as u32;
+                let _ = writeln!(
+                    svg,
+                    r#"<polyline points="{}" fill="none" stroke="{}" stroke-opacity="0.45" stroke-width="{:.1}" stroke-linecap="round"/>"#,
+                    points.join(" "),
+                    color(number),
+                    SIZE * 0.6
+                );
+            }
+        }
+
+        if options.score_overlay {
+            for line in &lines {
+                // one step before the first field of the line
+                let (x0, y0) = center(&line.fields[0], radius);
+                let (x1, y1) = center(&line.fields[1], radius);
+                let (x, y) = (x0 - (x1 - x0) * 0.95, y0 - (y1 - y0) * 0.95);
+                let _ = writeln!(
+                    svg,
+                    r##"<text x="{:.1}" y="{:.1}" font-size="{:.0}" text-anchor="middle" dominant-baseline="central" fill="#f5f0e6">{}</text>"##,
+                    x,
+                    y,
+                    SIZE * 0.36,
+                    line.score
+                );
+            }
+            let _ = writeln!(
+                svg,
+                r##"<text x="{:.1}" y="{:.1}" font-size="{:.0}" font-weight="bold" text-anchor="middle" fill="#f5f0e6">Score: {}</text>"##,
+                width / 2.0,
+                height - 20.0,
+                SIZE * 0.5,
+                self.score()
+            );
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    #[cfg(feature = "png")]
+    pub fn to_png(&self, options: &SvgOptions) -> Result<Vec<u8>, String> {
+        use resvg::{tiny_skia, usvg};
+
+        let mut svg_options = usvg::Options::default();
+        svg_options.fontdb_mut().load_system_fonts();
+        let tree =
+            usvg::Tree::from_str(&self.to_svg(options), &svg_options).map_err(|e| e.to_string())?;
+        let size = tree.size().to_int_size();
+        let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+            .ok_or("cannot create image".to_string())?;
+        resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+        pixmap.encode_png().map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,168 @@
+use crate::rules::RuleSet;
+use core::fmt;
+use strum_macros::EnumIter;
+
+#[derive(PartialEq, Eq, Debug, Copy, Hash, Clone, EnumIter)]
+pub enum Direction {
+    Top, // from top to bottom
+    Left, // from down left to up right
+    Right, // from down right to up left
+}
+
+#[deprecated(note = "the numbers depend on the rule set, use `RuleSet::max_number`")]
+pub fn max_number(direction: &Direction) -> u32 {
+    RuleSet::CLASSIC.max_number(direction)
+}
+
+// color of the bar of a number on the physical tiles
+pub fn number_color(number: u32) -> (u8, u8, u8) {
+    match number {
+        1 => (158, 158, 158), // grey
+        2 => (240, 150, 190), // pink
+        3 => (236, 110, 150), // rose
+        4 => (90, 180, 230),  // light blue
+        5 => (40, 190, 180),  // turquoise
+        6 => (225, 50, 45),   // red
+        7 => (120, 190, 70),  // green
+        8 => (245, 140, 30),  // orange
+        9 => (250, 215, 40),  // yellow
+        _ => (255, 255, 255),
+    }
+}
+
+// The numbers of the classic game per direction, from before rule sets. Tiles
+// hold plain numbers now, `RuleSet::numbers` lists them for any rule set. The
+// enums convert to the numbers and `Tile::classic` builds a tile from them.
+#[allow(deprecated)]
+mod classic {
+    use strum_macros::EnumIter;
+
+    #[deprecated(note = "the numbers depend on the rule set, use `RuleSet::numbers`")]
+    #[repr(i32)]
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Hash, Clone, EnumIter)]
+    pub enum NumTop {
+        N1 = 1,
+        N5 = 5,
+        N9 = 9,
+    }
+
+    impl From<NumTop> for u32 {
+        fn from(num: NumTop) -> u32 {
+            num as u32
+        }
+    }
+
+    impl NumTop {
+        #[allow(clippy::result_unit_err)]
+        pub fn from_int(num: i32) -> Result<NumTop, ()> {
+            match num {
+                1 => Ok(NumTop::N1),
+                5 => Ok(NumTop::N5),
+                9 => Ok(NumTop::N9),
+                _ => Err(()),
+            }
+        }
+    }
+
+    #[deprecated(note = "the numbers depend on the rule set, use `RuleSet::numbers`")]
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Hash, Clone, EnumIter)]
+    pub enum NumLeft {
+        N2 = 2,
+        N6 = 6,
+        N7 = 7,
+    }
+
+    impl From<NumLeft> for u32 {
+        fn from(num: NumLeft) -> u32 {
+            num as u32
+        }
+    }
+
+    impl NumLeft {
+        #[allow(clippy::result_unit_err)]
+        pub fn from_int(num: i32) -> Result<NumLeft, ()> {
+            match num {
+                2 => Ok(NumLeft::N2),
+                6 => Ok(NumLeft::N6),
+                7 => Ok(NumLeft::N7),
+                _ => Err(()),
+            }
+        }
+    }
+
+    #[deprecated(note = "the numbers depend on the rule set, use `RuleSet::numbers`")]
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Hash, Clone, EnumIter)]
+    pub enum NumRight {
+        N3 = 3,
+        N4 = 4,
+        N8 = 8,
+    }
+
+    impl From<NumRight> for u32 {
+        fn from(num: NumRight) -> u32 {
+            num as u32
+        }
+    }
+
+    impl NumRight {
+        #[allow(clippy::result_unit_err)]
+        pub fn from_int(num: i32) -> Result<NumRight, ()> {
+            match num {
+                3 => Ok(NumRight::N3),
+                4 => Ok(NumRight::N4),
+                8 => Ok(NumRight::N8),
+                _ => Err(()),
+            }
+        }
+    }
+}
+
+#[allow(deprecated)]
+pub use classic::{NumLeft, NumRight, NumTop};
+
+// `RuleSet::tile` checks the numbers against a rule set, building a `Tile`
+// directly does not
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Debug)]
+pub struct Tile {
+    pub top: u32,
+    pub left: u32,
+    pub right: u32,
+}
+
+impl Tile {
+    // a tile of the classic game, like the struct literal before rule sets
+    #[deprecated(note = "the numbers depend on the rule set, use `RuleSet::tile`")]
+    #[allow(deprecated)]
+    pub fn classic(top: NumTop, left: NumLeft, right: NumRight) -> Tile {
+        Tile {
+            top: top.into(),
+            left: left.into(),
+            right: right.into(),
+        }
+    }
+
+    pub fn number(&self, direction: &Direction) -> u32 {
+        match direction {
+            Direction::Top => self.top,
+            Direction::Left => self.left,
+            Direction::Right => self.right,
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Tile({} {} {})", self.top, self.left, self.right)
+    }
+}
+
+// a tile of the classic game, panics for other numbers like `RuleSet::tile`
+// with `unwrap`
+#[macro_export]
+macro_rules! tile {
+    ($top:expr, $left:expr, $right:expr) => {
+        $crate::rules::RuleSet::CLASSIC
+            .tile($top, $left, $right)
+            .unwrap()
+    };
+}
@@ -0,0 +1,114 @@
+#ifndef TAKEITEASY_H
+#define TAKEITEASY_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit. Regenerate with
+   UPDATE_HEADER=1 cargo test -p takeiteasy-cdylib --test ffi_tests */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum TieStatus {
+  TIE_STATUS_OK = 0,
+  TIE_STATUS_NULL_POINTER = 1,
+  TIE_STATUS_INVALID_FIELD = 2,
+  TIE_STATUS_INVALID_TILE = 3,
+  TIE_STATUS_CANNOT_PLACE = 4,
+  TIE_STATUS_GAME_FINISHED = 5,
+  TIE_STATUS_BUFFER_TOO_SMALL = 6,
+  TIE_STATUS_INTERNAL_ERROR = 7,
+  TIE_STATUS_INVALID_DEPTH = 8,
+} TieStatus;
+
+typedef struct TieBoard TieBoard;
+
+typedef struct TieGame TieGame;
+
+typedef struct TieField {
+  int32_t column;
+  int32_t row;
+} TieField;
+
+typedef struct TieTile {
+  int32_t top;
+  int32_t left;
+  int32_t right;
+} TieTile;
+
+typedef struct TieLine {
+  int32_t direction;
+  int32_t length;
+  uint32_t score;
+  bool broken;
+} TieLine;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct TieBoard *tie_board_new(void);
+
+/**
+ * # Safety
+ * `board` is null or a handle from `tie_board_new` or `tie_game_board` that
+ * is not freed yet.
+ */
+void tie_board_free(struct TieBoard *board);
+
+enum TieStatus tie_board_place_tile(struct TieBoard *board,
+                                    struct TieField field,
+                                    struct TieTile tile);
+
+enum TieStatus tie_board_tile_at(const struct TieBoard *board,
+                                 struct TieField field,
+                                 struct TieTile *tile,
+                                 bool *placed);
+
+enum TieStatus tie_board_score(const struct TieBoard *board, uint32_t *score);
+
+enum TieStatus tie_board_max_score(const struct TieBoard *board, uint32_t *score);
+
+enum TieStatus tie_board_lines(const struct TieBoard *board,
+                               struct TieLine *lines,
+                               size_t capacity,
+                               size_t *count);
+
+enum TieStatus tie_board_remaining_tiles(const struct TieBoard *board,
+                                         struct TieTile *tiles,
+                                         size_t capacity,
+                                         size_t *count);
+
+enum TieStatus tie_board_best_field(const struct TieBoard *board,
+                                    struct TieTile tile,
+                                    int32_t depth,
+                                    struct TieField *field);
+
+struct TieGame *tie_game_new(uint64_t seed);
+
+/**
+ * # Safety
+ * `game` is null or a handle from `tie_game_new` that is not freed yet.
+ */
+void tie_game_free(struct TieGame *game);
+
+enum TieStatus tie_game_current_tile(const struct TieGame *game, struct TieTile *tile);
+
+enum TieStatus tie_game_place_tile(struct TieGame *game, struct TieField field);
+
+enum TieStatus tie_game_finished(const struct TieGame *game, bool *finished);
+
+enum TieStatus tie_game_score(const struct TieGame *game, uint32_t *score);
+
+enum TieStatus tie_game_board(const struct TieGame *game, struct TieBoard **board);
+
+enum TieStatus tie_game_best_field(const struct TieGame *game,
+                                   int32_t depth,
+                                   struct TieField *field);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* TAKEITEASY_H */
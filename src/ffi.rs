@@ -0,0 +1,353 @@
+use crate::ai::AI;
+use crate::rules::RuleSet;
+use crate::tile::{Direction, Tile};
+use crate::{Board, Coordinate, Field, Game};
+use std::panic::{self, AssertUnwindSafe};
+
+// C API for the cdylib, behind the `ffi` feature. The header is
+// include/takeiteasy.h, generated from this file by cbindgen (see
+// crates/cdylib/tests/ffi_tests.rs).
+//
+// Boards and games are opaque handles, created by `tie_*_new` and released by
+// `tie_*_free`. Every other function returns a `TieStatus` and writes its
+// result through a pointer. Panics do not cross the boundary, they are
+// reported as `TIE_STATUS_INTERNAL_ERROR`.
+//
+// Only the classic game is covered: tiles and fields are checked against the
+// classic rules, other rule sets are not available over the C API.
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieStatus {
+    Ok = 0,
+    NullPointer = 1,
+    InvalidField = 2,
+    InvalidTile = 3,
+    // the field is taken or the tile is already on the board
+    CannotPlace = 4,
+    GameFinished = 5,
+    BufferTooSmall = 6,
+    InternalError = 7,
+    // a negative search depth
+    InvalidDepth = 8,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct TieTile {
+    pub top: i32,
+    pub left: i32,
+    pub right: i32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct TieField {
+    pub column: i32,
+    pub row: i32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct TieLine {
+    // 0 top, 1 left, 2 right
+    pub direction: i32,
+    pub length: i32,
+    pub score: u32,
+    pub broken: bool,
+}
+
+pub struct TieBoard {
+    board: Board,
+}
+
+pub struct TieGame {
+    game: Game,
+}
+
+fn guard(f: impl FnOnce() -> Result<(), TieStatus>) -> TieStatus {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => TieStatus::Ok,
+        Ok(Err(status)) => status,
+        Err(_) => TieStatus::InternalError,
+    }
+}
+
+fn reference<'a, T>(pointer: *const T) -> Result<&'a T, TieStatus> {
+    unsafe { pointer.as_ref() }.ok_or(TieStatus::NullPointer)
+}
+
+fn mutable<'a, T>(pointer: *mut T) -> Result<&'a mut T, TieStatus> {
+    unsafe { pointer.as_mut() }.ok_or(TieStatus::NullPointer)
+}
+
+fn check_depth(depth: i32) -> Result<i32, TieStatus> {
+    if depth < 0 {
+        return Err(TieStatus::InvalidDepth);
+    }
+    Ok(depth)
+}
+
+fn write<T>(pointer: *mut T, value: T) -> Result<(), TieStatus> {
+    *mutable(pointer)? = value;
+    Ok(())
+}
+
+// copies the values to a buffer of `capacity` elements, `count` is set even
+// when the buffer is too small
+fn write_all<T: Copy>(
+    values: &[T],
+    buffer: *mut T,
+    capacity: usize,
+    count: *mut usize,
+) -> Result<(), TieStatus> {
+    write(count, values.len())?;
+    if values.len() > capacity {
+        return Err(TieStatus::BufferTooSmall);
+    }
+    if !values.is_empty() {
+        if buffer.is_null() {
+            return Err(TieStatus::NullPointer);
+        }
+        unsafe { std::ptr::copy_nonoverlapping(values.as_ptr(), buffer, values.len()) };
+    }
+    Ok(())
+}
+
+impl TieTile {
+    fn from(tile: &Tile) -> TieTile {
+        TieTile {
+            top: tile.top as i32,
+            left: tile.left as i32,
+            right: tile.right as i32,
+        }
+    }
+
+    fn to_tile(self) -> Result<Tile, TieStatus> {
+        // negative numbers wrap around and are rejected as well
+        RuleSet::default()
+            .tile(self.top as u32, self.left as u32, self.right as u32)
+            .map_err(|_| TieStatus::InvalidTile)
+    }
+}
+
+impl TieField {
+    fn from(field: &Field) -> TieField {
+        TieField {
+            column: field.column as i32,
+            row: field.row as i32,
+        }
+    }
+
+    fn to_field(self) -> Result<Field, TieStatus> {
+        let coordinate = |n: i32| Coordinate::from_int(n).map_err(|_| TieStatus::InvalidField);
+        Field::new(coordinate(self.column)?, coordinate(self.row)?)
+            .map_err(|_| TieStatus::InvalidField)
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn tie_board_new() -> *mut TieBoard {
+    Box::into_raw(Box::new(TieBoard {
+        board: Board::new(),
+    }))
+}
+
+/// # Safety
+/// `board` is null or a handle from `tie_board_new` or `tie_game_board` that
+/// is not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn tie_board_free(board: *mut TieBoard) {
+    if !board.is_null() {
+        drop(Box::from_raw(board));
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn tie_board_place_tile(
+    board: *mut TieBoard,
+    field: TieField,
+    tile: TieTile,
+) -> TieStatus {
+    guard(|| {
+        let board = &mut mutable(board)?.board;
+        board
+            .place_tile(field.to_field()?, tile.to_tile()?)
+            .map_err(|_| TieStatus::CannotPlace)
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn tie_board_tile_at(
+    board: *const TieBoard,
+    field: TieField,
+    tile: *mut TieTile,
+    placed: *mut bool,
+) -> TieStatus {
+    guard(|| {
+        let board = &reference(board)?.board;
+        let found = board.tiles.get(&field.to_field()?).copied().flatten();
+        write(placed, found.is_some())?;
+        if let Some(found) = found {
+            write(tile, TieTile::from(&found))?;
+        }
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn tie_board_score(board: *const TieBoard, score: *mut u32) -> TieStatus {
+    guard(|| write(score, reference(board)?.board.score()))
+}
+
+// highest score the board can still reach
+#[no_mangle]
+pub extern "C" fn tie_board_max_score(board: *const TieBoard, score: *mut u32) -> TieStatus {
+    guard(|| write(score, reference(board)?.board.max_score()))
+}
+
+// the 15 scoring lines
+#[no_mangle]
+pub extern "C" fn tie_board_lines(
+    board: *const TieBoard,
+    lines: *mut TieLine,
+    capacity: usize,
+    count: *mut usize,
+) -> TieStatus {
+    guard(|| {
+        let board = &reference(board)?.board;
+        let values: Vec<TieLine> = board
+            .line_scores()
+            .iter()
+            .map(|line| TieLine {
+                direction: match line.direction {
+                    Direction::Top => 0,
+                    Direction::Left => 1,
+                    Direction::Right => 2,
+                },
+                length: line.fields.len() as i32,
+                score: line.score,
+                broken: line.broken,
+            })
+            .collect();
+        write_all(&values, lines, capacity, count)
+    })
+}
+
+// tiles not on the board, sorted
+#[no_mangle]
+pub extern "C" fn tie_board_remaining_tiles(
+    board: *const TieBoard,
+    tiles: *mut TieTile,
+    capacity: usize,
+    count: *mut usize,
+) -> TieStatus {
+    guard(|| {
+        let mut remaining: Vec<Tile> = reference(board)?
+            .board
+            .remaining_tiles()
+            .into_iter()
+            .collect();
+        remaining.sort();
+        let values: Vec<TieTile> = remaining.iter().map(TieTile::from).collect();
+        write_all(&values, tiles, capacity, count)
+    })
+}
+
+// field the AI would place the tile on
+#[no_mangle]
+pub extern "C" fn tie_board_best_field(
+    board: *const TieBoard,
+    tile: TieTile,
+    depth: i32,
+    field: *mut TieField,
+) -> TieStatus {
+    guard(|| {
+        let board = &reference(board)?.board;
+        let best = AI::best_field(board, tile.to_tile()?, check_depth(depth)?)
+            .map_err(|_| TieStatus::CannotPlace)?;
+        write(field, TieField::from(&best))
+    })
+}
+
+// games with the same seed draw the same tiles
+#[no_mangle]
+pub extern "C" fn tie_game_new(seed: u64) -> *mut TieGame {
+    Box::into_raw(Box::new(TieGame {
+        game: Game::with_seed(seed),
+    }))
+}
+
+/// # Safety
+/// `game` is null or a handle from `tie_game_new` that is not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn tie_game_free(game: *mut TieGame) {
+    if !game.is_null() {
+        drop(Box::from_raw(game));
+    }
+}
+
+// TIE_STATUS_GAME_FINISHED once all fields are taken
+#[no_mangle]
+pub extern "C" fn tie_game_current_tile(game: *const TieGame, tile: *mut TieTile) -> TieStatus {
+    guard(|| {
+        let game = &reference(game)?.game;
+        match game.current_tile {
+            Some(current) if !game.finished() => write(tile, TieTile::from(&current)),
+            _ => Err(TieStatus::GameFinished),
+        }
+    })
+}
+
+// places the current tile and draws the next one
+#[no_mangle]
+pub extern "C" fn tie_game_place_tile(game: *mut TieGame, field: TieField) -> TieStatus {
+    guard(|| {
+        let game = &mut mutable(game)?.game;
+        let field = field.to_field()?;
+        if game.finished() {
+            return Err(TieStatus::GameFinished);
+        }
+        game.place_tile(field).map_err(|_| TieStatus::CannotPlace)
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn tie_game_finished(game: *const TieGame, finished: *mut bool) -> TieStatus {
+    guard(|| write(finished, reference(game)?.game.finished()))
+}
+
+#[no_mangle]
+pub extern "C" fn tie_game_score(game: *const TieGame, score: *mut u32) -> TieStatus {
+    guard(|| write(score, reference(game)?.game.board.score()))
+}
+
+// copy of the board of the game, released with `tie_board_free`
+#[no_mangle]
+pub extern "C" fn tie_game_board(game: *const TieGame, board: *mut *mut TieBoard) -> TieStatus {
+    guard(|| {
+        let copy = TieBoard {
+            board: reference(game)?.game.board.clone(),
+        };
+        write(board, Box::into_raw(Box::new(copy)))
+    })
+}
+
+// field the AI would place the current tile on
+#[no_mangle]
+pub extern "C" fn tie_game_best_field(
+    game: *const TieGame,
+    depth: i32,
+    field: *mut TieField,
+) -> TieStatus {
+    guard(|| {
+        let game = &reference(game)?.game;
+        let tile = match game.current_tile {
+            Some(tile) if !game.finished() => tile,
+            _ => return Err(TieStatus::GameFinished),
+        };
+        let best = AI::best_field(&game.board, tile, check_depth(depth)?)
+            .map_err(|_| TieStatus::CannotPlace)?;
+        write(field, TieField::from(&best))
+    })
+}
@@ -0,0 +1,432 @@
+// the pyo3 macros convert the errors of `PyResult` functions into `PyErr`
+#![allow(clippy::useless_conversion)]
+
+use crate::ai::AI;
+use crate::render::Style;
+use crate::rules::RuleSet;
+use crate::tile::{Direction, Tile};
+use crate::{Board, Coordinate, Field, Game};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+// Python module behind the `python` feature, built with maturin:
+//
+//     maturin develop --release
+//
+// `simulate` plays many games without the GIL and returns plain lists, so
+// `numpy.asarray` turns them into arrays:
+//
+//     import numpy as np, takeiteasy
+//     result = takeiteasy.simulate(10000, depth=0, seed=1)
+//     scores = np.asarray(result["scores"])   # shape (10000,)
+//     lines = np.asarray(result["lines"])     # shape (10000, 15)
+//     boards = np.asarray(result["boards"])   # shape (10000, 19, 3)
+//
+// Only the classic game is covered: tiles, fields, boards and new games follow
+// the classic rules.
+
+fn value_error(message: String) -> PyErr {
+    PyValueError::new_err(message)
+}
+
+fn check_depth(depth: i32) -> PyResult<i32> {
+    if depth < 0 {
+        return Err(value_error(format!("invalid depth: {}", depth)));
+    }
+    Ok(depth)
+}
+
+fn direction_name(direction: &Direction) -> &'static str {
+    match direction {
+        Direction::Top => "top",
+        Direction::Left => "left",
+        Direction::Right => "right",
+    }
+}
+
+#[pyclass(name = "Tile", module = "takeiteasy", frozen, eq, hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PyTile {
+    tile: Tile,
+}
+
+#[pymethods]
+impl PyTile {
+    #[new]
+    fn new(top: i32, left: i32, right: i32) -> PyResult<PyTile> {
+        let invalid = || value_error(format!("invalid tile: {} {} {}", top, left, right));
+        // negative numbers wrap around and are rejected as well
+        let tile = RuleSet::default()
+            .tile(top as u32, left as u32, right as u32)
+            .map_err(|_| invalid())?;
+        Ok(PyTile { tile })
+    }
+
+    // record notation, e.g. "973"
+    #[staticmethod]
+    fn parse(text: &str) -> PyResult<PyTile> {
+        crate::record::parse_tile(text)
+            .map(|tile| PyTile { tile })
+            .map_err(value_error)
+    }
+
+    #[getter]
+    fn top(&self) -> u32 {
+        self.tile.top
+    }
+
+    #[getter]
+    fn left(&self) -> u32 {
+        self.tile.left
+    }
+
+    #[getter]
+    fn right(&self) -> u32 {
+        self.tile.right
+    }
+
+    fn numbers(&self) -> (u32, u32, u32) {
+        (self.top(), self.left(), self.right())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Tile({}, {}, {})", self.top(), self.left(), self.right())
+    }
+}
+
+#[pyclass(name = "Field", module = "takeiteasy", frozen, eq, hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PyField {
+    field: Field,
+}
+
+#[pymethods]
+impl PyField {
+    #[new]
+    fn new(column: i32, row: i32) -> PyResult<PyField> {
+        let invalid = || value_error(format!("invalid field: {}, {}", column, row));
+        let column = Coordinate::from_int(column).map_err(|_| invalid())?;
+        let row = Coordinate::from_int(row).map_err(|_| invalid())?;
+        Field::new(column, row)
+            .map(|field| PyField { field })
+            .map_err(value_error)
+    }
+
+    // "3 2", "c3r2", "center", an id letter, ...
+    #[staticmethod]
+    fn parse(text: &str) -> PyResult<PyField> {
+        text.parse::<Field>()
+            .map(|field| PyField { field })
+            .map_err(value_error)
+    }
+
+    #[getter]
+    fn column(&self) -> i32 {
+        self.field.column as i32
+    }
+
+    #[getter]
+    fn row(&self) -> i32 {
+        self.field.row as i32
+    }
+
+    #[getter]
+    fn id(&self) -> char {
+        self.field.id()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Field({}, {})", self.column(), self.row())
+    }
+}
+
+#[pyclass(name = "Board", module = "takeiteasy")]
+#[derive(Clone)]
+pub struct PyBoard {
+    board: Board,
+}
+
+#[pymethods]
+impl PyBoard {
+    #[new]
+    fn new() -> PyBoard {
+        PyBoard {
+            board: Board::new(),
+        }
+    }
+
+    fn place_tile(&mut self, field: PyField, tile: PyTile) -> PyResult<()> {
+        self.board
+            .place_tile(field.field, tile.tile)
+            .map_err(|_| value_error(format!("cannot place {} on {}", tile.tile, field.field)))
+    }
+
+    fn tile_at(&self, field: PyField) -> Option<PyTile> {
+        self.board
+            .tiles
+            .get(&field.field)
+            .copied()
+            .flatten()
+            .map(|tile| PyTile { tile })
+    }
+
+    fn empty_fields(&self) -> Vec<PyField> {
+        self.board
+            .empty_fields()
+            .into_iter()
+            .map(|field| PyField { field })
+            .collect()
+    }
+
+    fn remaining_tiles(&self) -> Vec<PyTile> {
+        self.board
+            .remaining_tiles()
+            .into_iter()
+            .map(|tile| PyTile { tile })
+            .collect()
+    }
+
+    fn is_full(&self) -> bool {
+        self.board.is_full()
+    }
+
+    fn score(&self) -> u32 {
+        self.board.score()
+    }
+
+    fn max_score(&self) -> u32 {
+        self.board.max_score()
+    }
+
+    // one dict per scoring line
+    fn lines<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        self.board
+            .line_scores()
+            .iter()
+            .map(|line| {
+                let dict = PyDict::new_bound(py);
+                dict.set_item("direction", direction_name(&line.direction))?;
+                let fields: Vec<PyField> =
+                    line.fields.iter().map(|&field| PyField { field }).collect();
+                dict.set_item("fields", fields.into_py(py))?;
+                dict.set_item("score", line.score)?;
+                dict.set_item("broken", line.broken)?;
+                Ok(dict)
+            })
+            .collect()
+    }
+
+    // expected final score of the board, see `AI::estimated_score`
+    #[pyo3(signature = (depth=0))]
+    fn estimated_score(&self, depth: i32) -> PyResult<f64> {
+        Ok(AI::estimated_score(&self.board, check_depth(depth)?).unwrap())
+    }
+
+    // expected final score for each empty field the tile can go to
+    #[pyo3(signature = (tile, depth=0))]
+    fn evaluate(&self, tile: PyTile, depth: i32) -> PyResult<Vec<(PyField, f64)>> {
+        let depth = check_depth(depth)?;
+        let mut evaluations = Vec::new();
+        for field in self.empty_fields() {
+            let board = self
+                .board
+                .place_tile_on_new_board(field.field, tile.tile)
+                .map_err(|_| value_error(format!("{} is already on the board", tile.tile)))?;
+            evaluations.push((field, AI::estimated_score(&board, depth).unwrap()));
+        }
+        Ok(evaluations)
+    }
+
+    #[pyo3(signature = (tile, depth=1))]
+    fn best_field(&self, py: Python<'_>, tile: PyTile, depth: i32) -> PyResult<PyField> {
+        let depth = check_depth(depth)?;
+        py.allow_threads(|| AI::best_field(&self.board, tile.tile, depth))
+            .map(|field| PyField { field })
+            .map_err(|_| value_error(format!("no field for {}", tile.tile)))
+    }
+
+    fn render(&self) -> String {
+        self.board.view(Style::Large).to_string()
+    }
+
+    fn __str__(&self) -> String {
+        self.render()
+    }
+}
+
+#[pyclass(name = "Game", module = "takeiteasy")]
+pub struct PyGame {
+    game: Game,
+}
+
+#[pymethods]
+impl PyGame {
+    // games with the same seed draw the same tiles
+    #[new]
+    #[pyo3(signature = (seed=None))]
+    fn new(seed: Option<u64>) -> PyGame {
+        PyGame {
+            game: Game::with_seed(seed.unwrap_or_else(rand::random)),
+        }
+    }
+
+    #[staticmethod]
+    fn load(text: &str) -> PyResult<PyGame> {
+        Game::load(text)
+            .map(|game| PyGame { game })
+            .map_err(value_error)
+    }
+
+    fn save(&self) -> String {
+        self.game.save()
+    }
+
+    #[getter]
+    fn seed(&self) -> u64 {
+        self.game.seed()
+    }
+
+    // None once the game is finished
+    #[getter]
+    fn current_tile(&self) -> Option<PyTile> {
+        match self.game.current_tile {
+            Some(tile) if !self.game.finished() => Some(PyTile { tile }),
+            _ => None,
+        }
+    }
+
+    #[getter]
+    fn board(&self) -> PyBoard {
+        PyBoard {
+            board: self.game.board.clone(),
+        }
+    }
+
+    #[getter]
+    fn moves(&self) -> Vec<(PyTile, PyField)> {
+        self.game
+            .moves()
+            .iter()
+            .map(|&(tile, field)| (PyTile { tile }, PyField { field }))
+            .collect()
+    }
+
+    fn place_tile(&mut self, field: PyField) -> PyResult<()> {
+        self.game
+            .check_placement(&field.field)
+            .map_err(value_error)?;
+        self.game
+            .place_tile(field.field)
+            .map_err(|_| value_error(format!("cannot place on {}", field.field)))
+    }
+
+    fn finished(&self) -> bool {
+        self.game.finished()
+    }
+
+    fn score(&self) -> u32 {
+        self.game.board.score()
+    }
+
+    // field the AI would place the current tile on
+    #[pyo3(signature = (depth=1))]
+    fn hint(&self, py: Python<'_>, depth: i32) -> PyResult<PyField> {
+        let tile = self
+            .current_tile()
+            .ok_or_else(|| value_error("the game is finished".to_string()))?;
+        self.board().best_field(py, tile, depth)
+    }
+}
+
+struct Simulation {
+    seeds: Vec<u64>,
+    scores: Vec<u32>,
+    lines: Vec<Vec<u32>>,
+    boards: Vec<Vec<[u32; 3]>>,
+}
+
+fn simulate_games(games: usize, depth: i32, seed: u64) -> Simulation {
+    let fields = Board::all_fields();
+    let mut simulation = Simulation {
+        seeds: Vec::with_capacity(games),
+        scores: Vec::with_capacity(games),
+        lines: Vec::with_capacity(games),
+        boards: Vec::with_capacity(games),
+    };
+    for i in 0..games {
+        let seed = seed.wrapping_add(i as u64);
+        let mut game = Game::with_seed(seed);
+        while !game.finished() {
+            let tile = game.current_tile.unwrap();
+            let field = AI::best_field(&game.board, tile, depth).unwrap();
+            game.place_tile(field).unwrap();
+        }
+        let board = &game.board;
+        simulation.seeds.push(seed);
+        simulation.scores.push(board.score());
+        simulation
+            .lines
+            .push(board.line_scores().iter().map(|line| line.score).collect());
+        simulation.boards.push(
+            fields
+                .iter()
+                .map(|field| {
+                    let tile = board.tiles[field].unwrap();
+                    [tile.top, tile.left, tile.right]
+                })
+                .collect(),
+        );
+    }
+    simulation
+}
+
+// Lets the AI play `games` games, the seeds count up from `seed`. Returns a
+// dict of lists: "seeds" and "scores" per game, "lines" with the 15 line
+// scores per game and "boards" with the numbers of the final tiles per game,
+// fields in the order of `fields()`.
+#[pyfunction]
+#[pyo3(signature = (games, depth=0, seed=None))]
+fn simulate(
+    py: Python<'_>,
+    games: usize,
+    depth: i32,
+    seed: Option<u64>,
+) -> PyResult<Bound<'_, PyDict>> {
+    let depth = check_depth(depth)?;
+    let seed = seed.unwrap_or_else(rand::random);
+    let simulation = py.allow_threads(|| simulate_games(games, depth, seed));
+    let result = PyDict::new_bound(py);
+    result.set_item("seeds", simulation.seeds)?;
+    result.set_item("scores", simulation.scores)?;
+    result.set_item("lines", simulation.lines)?;
+    result.set_item("boards", simulation.boards)?;
+    Ok(result)
+}
+
+// all fields, sorted by column and row
+#[pyfunction]
+fn fields() -> Vec<PyField> {
+    Board::all_fields()
+        .into_iter()
+        .map(|field| PyField { field })
+        .collect()
+}
+
+// all 27 tiles, sorted
+#[pyfunction]
+fn tiles() -> Vec<PyTile> {
+    PyBoard::new().remaining_tiles()
+}
+
+#[pymodule]
+pub fn takeiteasy(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyTile>()?;
+    module.add_class::<PyField>()?;
+    module.add_class::<PyBoard>()?;
+    module.add_class::<PyGame>()?;
+    module.add_function(wrap_pyfunction!(simulate, module)?)?;
+    module.add_function(wrap_pyfunction!(fields, module)?)?;
+    module.add_function(wrap_pyfunction!(tiles, module)?)?;
+    Ok(())
+}
@@ -0,0 +1,319 @@
+use crate::rules::RuleSet;
+use crate::session::Session;
+use crate::{Coordinate, Field};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io;
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tungstenite::{Error, Message};
+
+// WebSocket rooms for games over the network. Every connection joins one
+// room, the players of a room play on one shared draw (see `Session`).
+//
+// client messages:
+//     {"type": "join", "room": "friday", "player": "alice"}
+//     {"type": "start", "seed": 42,                     only the host can start,
+//      "rules": {"top": [1, 5, 9], "left": [2, 6, 7],  seed and rules are
+//                "right": [3, 4, 8], "radius": 1}}      optional
+//     {"type": "place", "field": {"column": 3, "row": 2}}
+//
+// events, sent to everyone in the room:
+//     {"event": "joined", "player": ..., "players": [...]}
+//     {"event": "left", "player": ..., "players": [...]}
+//     {"event": "started", "seed": ..., "rules": ..., "players": [...]}
+//     {"event": "tile", "round": ..., "tile": [9, 7, 3]}  tile drawn
+//     {"event": "placed", "player": ..., "field": ..., "tile": ..., "score": ...,
+//      "waiting_for": [...]}
+//     {"event": "ready", "round": ...}                  everyone placed the tile
+//     {"event": "game_over", "standings": [{"name": ..., "score": ...}]}
+//
+// Invalid messages are answered with {"event": "error", "message": ...} to
+// the sender only. Players can only join before the game of the room starts.
+// The host is the member that joined first, after it leaves the next one. A
+// player that leaves during the game is out of it, the others go on without
+// waiting for it.
+
+// how often a connection looks for events while waiting for messages
+const POLL: Duration = Duration::from_millis(20);
+
+struct Member {
+    name: String,
+    events: Sender<String>,
+}
+
+#[derive(Default)]
+struct Room {
+    members: Vec<Member>,
+    session: Option<Session>,
+}
+
+type Rooms = Arc<Mutex<HashMap<String, Room>>>;
+
+pub struct RoomServer {
+    listener: TcpListener,
+    rooms: Rooms,
+}
+
+impl Room {
+    fn names(&self) -> Vec<String> {
+        self.members.iter().map(|m| m.name.clone()).collect()
+    }
+
+    fn broadcast(&self, event: Value) {
+        let text = event.to_string();
+        for member in &self.members {
+            let _ = member.events.send(text.clone());
+        }
+    }
+
+    fn broadcast_tile(&self, session: &Session) {
+        self.broadcast(json!({
+            "event": "tile",
+            "round": session.round(),
+            "tile": session.current_tile(),
+        }));
+    }
+
+    fn start(&mut self, name: &str, rules: RuleSet, seed: u64) -> Result<(), String> {
+        if self.session.is_some() {
+            return Err("the game has started".to_string());
+        }
+        let host = &self.members[0].name;
+        if host != name {
+            return Err(format!("only {} can start the game", host));
+        }
+        let session = Session::with_rules(&self.names(), rules, seed)?;
+        self.broadcast(json!({
+            "event": "started",
+            "seed": seed,
+            "rules": rules,
+            "players": self.names(),
+        }));
+        self.broadcast_tile(&session);
+        self.session = Some(session);
+        Ok(())
+    }
+
+    fn place(&mut self, name: &str, field: &Value) -> Result<(), String> {
+        let Some(session) = self.session.as_mut() else {
+            return Err("the game has not started".to_string());
+        };
+        let field = parse_field(field, session.rules())?;
+        let round = session.round();
+        let tile = session.current_tile();
+        session.place(name, field)?;
+
+        let session = self.session.as_ref().unwrap();
+        self.broadcast(json!({
+            "event": "placed",
+            "player": name,
+            "field": field,
+            "tile": tile,
+            "score": session.player(name)?.game.board.score(),
+            "waiting_for": session.waiting_for(),
+        }));
+        self.end_round(round);
+        Ok(())
+    }
+
+    fn leave(&mut self, name: &str) {
+        self.members.retain(|m| m.name != name);
+        self.broadcast(json!({
+            "event": "left",
+            "player": name,
+            "players": self.names(),
+        }));
+        let Some(session) = self.session.as_mut() else {
+            return;
+        };
+        let round = session.round();
+        if session.remove(name).is_ok() {
+            self.end_round(round);
+        }
+    }
+
+    // the events at the end of the round, if it ended
+    fn end_round(&self, round: usize) {
+        let session = self.session.as_ref().unwrap();
+        if session.round() > round {
+            self.broadcast(json!({ "event": "ready", "round": round }));
+            if session.finished() {
+                let standings: Vec<Value> = session
+                    .standings()
+                    .iter()
+                    .map(|(name, score)| json!({ "name": name, "score": score }))
+                    .collect();
+                self.broadcast(json!({ "event": "game_over", "standings": standings }));
+            } else {
+                self.broadcast_tile(session);
+            }
+        }
+    }
+}
+
+// {"column": 3, "row": 2} on the board of the rules
+fn parse_field(value: &Value, rules: &RuleSet) -> Result<Field, String> {
+    let coordinate = |name: &str| {
+        value[name]
+            .as_i64()
+            .and_then(|n| i32::try_from(n).ok())
+            .and_then(|n| Coordinate::from_int(n).ok())
+            .ok_or(format!("invalid field: {}", value))
+    };
+    rules.field(coordinate("column")?, coordinate("row")?)
+}
+
+// room and player name of a connection
+struct Connection {
+    rooms: Rooms,
+    events: Sender<String>,
+    joined: Option<(String, String)>,
+}
+
+impl Connection {
+    fn join(&mut self, room: &str, player: &str) -> Result<(), String> {
+        if self.joined.is_some() {
+            return Err("already joined a room".to_string());
+        }
+        if room.trim().is_empty() || player.trim().is_empty() {
+            return Err("room and player must not be empty".to_string());
+        }
+        let mut rooms = self.rooms.lock().unwrap();
+        let entry = rooms.entry(room.to_string()).or_default();
+        if entry.session.is_some() {
+            return Err(format!("the game in room {} has started", room));
+        }
+        if entry.members.iter().any(|m| m.name == player) {
+            return Err(format!("{} is already in room {}", player, room));
+        }
+        entry.members.push(Member {
+            name: player.to_string(),
+            events: self.events.clone(),
+        });
+        entry.broadcast(json!({
+            "event": "joined",
+            "player": player,
+            "players": entry.names(),
+        }));
+        self.joined = Some((room.to_string(), player.to_string()));
+        Ok(())
+    }
+
+    fn leave(&mut self) {
+        let Some((room, player)) = self.joined.take() else {
+            return;
+        };
+        let mut rooms = self.rooms.lock().unwrap();
+        let Some(entry) = rooms.get_mut(&room) else {
+            return;
+        };
+        entry.leave(&player);
+        if entry.members.is_empty() {
+            rooms.remove(&room);
+        }
+    }
+
+    fn handle(&mut self, text: &str) -> Result<(), String> {
+        let message: Value =
+            serde_json::from_str(text).map_err(|e| format!("invalid JSON: {}", e))?;
+        let text = |name: &str| {
+            message[name]
+                .as_str()
+                .map(|s| s.to_string())
+                .ok_or(format!("missing {}", name))
+        };
+        let kind = text("type")?;
+        if kind == "join" {
+            return self.join(&text("room")?, &text("player")?);
+        }
+
+        let Some((room, player)) = self.joined.clone() else {
+            return Err("join a room first".to_string());
+        };
+        let mut rooms = self.rooms.lock().unwrap();
+        let room = rooms.get_mut(&room).unwrap();
+        match kind.as_str() {
+            "start" => {
+                let seed = match &message["seed"] {
+                    Value::Null => rand::random(),
+                    seed => seed.as_u64().ok_or("invalid seed")?,
+                };
+                let rules = match &message["rules"] {
+                    Value::Null => RuleSet::default(),
+                    rules => serde_json::from_value(rules.clone())
+                        .map_err(|e| format!("invalid rules: {}", e))?,
+                };
+                room.start(&player, rules, seed)
+            }
+            "place" => room.place(&player, &message["field"]),
+            _ => Err(format!("unknown message type: {}", kind)),
+        }
+    }
+}
+
+fn serve(rooms: Rooms, stream: TcpStream) {
+    let Ok(mut socket) = tungstenite::accept(stream) else {
+        return;
+    };
+    if socket.get_ref().set_read_timeout(Some(POLL)).is_err() {
+        return;
+    }
+    let (events, receiver) = mpsc::channel();
+    let mut connection = Connection {
+        rooms,
+        events,
+        joined: None,
+    };
+
+    'connection: loop {
+        while let Ok(event) = receiver.try_recv() {
+            if socket.send(Message::Text(event)).is_err() {
+                break 'connection;
+            }
+        }
+        match socket.read() {
+            Ok(Message::Text(text)) => {
+                if let Err(e) = connection.handle(&text) {
+                    let error = json!({ "event": "error", "message": e });
+                    let _ = connection.events.send(error.to_string());
+                }
+            }
+            // pings and the close handshake are answered by tungstenite
+            Ok(_) => {}
+            Err(Error::Io(e))
+                if e.kind() == io::ErrorKind::WouldBlock || e.kind() == io::ErrorKind::TimedOut => {
+            }
+            Err(_) => break,
+        }
+    }
+    connection.leave();
+}
+
+impl RoomServer {
+    // e.g. "127.0.0.1:8081", port 0 picks a free port
+    pub fn bind(address: &str) -> Result<RoomServer, String> {
+        let listener = TcpListener::bind(address).map_err(|e| e.to_string())?;
+        Ok(RoomServer {
+            listener,
+            rooms: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    pub fn port(&self) -> Option<u16> {
+        self.listener
+            .local_addr()
+            .ok()
+            .map(|address| address.port())
+    }
+
+    pub fn run(&self) {
+        for stream in self.listener.incoming().flatten() {
+            let rooms = self.rooms.clone();
+            thread::spawn(move || serve(rooms, stream));
+        }
+    }
+}
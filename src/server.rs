@@ -0,0 +1,376 @@
+use crate::ai::AI;
+use crate::rules::RuleSet;
+use crate::session::Session;
+use crate::tile::Tile;
+use crate::{Board, Coordinate, Field};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tiny_http::{Header, Method, Request, Response};
+
+// HTTP server with a JSON API behind the `server` feature:
+//
+//     POST   /games                {"players": ["alice", "bob"], "seed": 42,
+//                                   "rules": {"top": [1, 5, 9], "left": [2, 6, 7],
+//                                             "right": [3, 4, 8], "radius": 1}}
+//     GET    /games
+//     GET    /games/<id>
+//     DELETE /games/<id>
+//     POST   /games/<id>/place     {"player": "alice", "field": {"column": 3, "row": 2}}
+//     POST   /games/<id>/ai-move   {"player": "alice", "depth": 1}
+//     GET    /games/<id>/hint?player=alice&depth=1
+//     GET    /games/<id>/lines
+//
+// Without players a game is solo with the player "player", without seed the
+// seed is random, without rules the game is classic. All players of a game
+// share the draw (see `Session`). Fields are checked against the rules of the
+// game. The AI searches up to depth 1.
+// Errors are answered with {"error": "..."}. Requests are handled by a fixed
+// number of threads, games are locked one by one and not during a search.
+
+const DEFAULT_DEPTH: i32 = 1;
+const MAX_DEPTH: i32 = 1;
+const WORKERS: usize = 8;
+
+struct Registry {
+    next_id: AtomicU64,
+    sessions: Mutex<HashMap<u64, Arc<Mutex<Session>>>>,
+}
+
+pub struct Server {
+    http: tiny_http::Server,
+    registry: Registry,
+}
+
+struct Reply {
+    status: u16,
+    body: Value,
+}
+
+fn reply(status: u16, body: Value) -> Result<Reply, Reply> {
+    Ok(Reply { status, body })
+}
+
+fn error(status: u16, message: impl Into<String>) -> Reply {
+    Reply {
+        status,
+        body: json!({ "error": message.into() }),
+    }
+}
+
+fn bad_request(message: String) -> Reply {
+    error(400, message)
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap()
+}
+
+fn state(id: u64, session: &Session) -> Value {
+    let waiting_for = session.waiting_for();
+    let players: Vec<Value> = session
+        .players
+        .iter()
+        .map(|p| {
+            json!({
+                "name": p.name,
+                "score": p.game.board.score(),
+                "placed": !waiting_for.contains(&p.name.as_str()),
+                "board": to_json(&p.game.board),
+            })
+        })
+        .collect();
+    let standings: Vec<Value> = session
+        .standings()
+        .iter()
+        .map(|(name, score)| json!({ "name": name, "score": score }))
+        .collect();
+    json!({
+        "id": id,
+        "seed": session.seed(),
+        "rules": to_json(session.rules()),
+        "round": session.round(),
+        "finished": session.finished(),
+        "current_tile": to_json(&session.current_tile()),
+        "waiting_for": waiting_for,
+        "players": players,
+        "standings": standings,
+    })
+}
+
+fn lines(board: &Board) -> Value {
+    let lines: Vec<Value> = board
+        .line_scores()
+        .iter()
+        .map(|line| {
+            json!({
+                "direction": format!("{:?}", line.direction).to_lowercase(),
+                "fields": to_json(&line.fields),
+                "score": line.score,
+                "broken": line.broken,
+            })
+        })
+        .collect();
+    Value::Array(lines)
+}
+
+fn body_json(body: &str) -> Result<Value, Reply> {
+    if body.trim().is_empty() {
+        return Ok(json!({}));
+    }
+    serde_json::from_str(body).map_err(|e| bad_request(format!("invalid JSON: {}", e)))
+}
+
+fn string_field<'a>(body: &'a Value, name: &str) -> Result<&'a str, Reply> {
+    body[name]
+        .as_str()
+        .ok_or_else(|| bad_request(format!("missing {}", name)))
+}
+
+fn depth(value: &Value) -> Result<i32, Reply> {
+    match value {
+        Value::Null => Ok(DEFAULT_DEPTH),
+        value => value
+            .as_i64()
+            .filter(|depth| (0..=MAX_DEPTH as i64).contains(depth))
+            .map(|depth| depth as i32)
+            .ok_or_else(|| bad_request(format!("depth must be between 0 and {}", MAX_DEPTH))),
+    }
+}
+
+// {"column": 3, "row": 2} on the board of the rules
+fn field(value: &Value, rules: &RuleSet) -> Result<Field, Reply> {
+    let invalid = || bad_request(format!("invalid field: {}", value));
+    let coordinate = |name: &str| {
+        value[name]
+            .as_i64()
+            .and_then(|n| i32::try_from(n).ok())
+            .and_then(|n| Coordinate::from_int(n).ok())
+            .ok_or_else(invalid)
+    };
+    rules
+        .field(coordinate("column")?, coordinate("row")?)
+        .map_err(bad_request)
+}
+
+// percent-decoding, `+` is a space
+fn decode(text: &str) -> Option<String> {
+    let mut bytes = Vec::new();
+    let mut rest = text.bytes();
+    while let Some(byte) = rest.next() {
+        bytes.push(match byte {
+            b'+' => b' ',
+            b'%' => {
+                let high = (rest.next()? as char).to_digit(16)?;
+                let low = (rest.next()? as char).to_digit(16)?;
+                (high * 16 + low) as u8
+            }
+            byte => byte,
+        });
+    }
+    String::from_utf8(bytes).ok()
+}
+
+fn query(url: &str) -> Result<HashMap<String, String>, Reply> {
+    let Some((_, query)) = url.split_once('?') else {
+        return Ok(HashMap::new());
+    };
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| match (decode(key), decode(value)) {
+            (Some(key), Some(value)) => Ok((key, value)),
+            _ => Err(bad_request(format!("invalid query: {}", query))),
+        })
+        .collect()
+}
+
+// board and tile for a search, the session is not locked during the search
+fn position(session: &Mutex<Session>, player: &str) -> Result<(Board, Tile, usize), Reply> {
+    let session = session.lock().unwrap();
+    let board = session
+        .player(player)
+        .map_err(bad_request)?
+        .game
+        .board
+        .clone();
+    let tile = session
+        .current_tile()
+        .ok_or_else(|| error(409, "the game is finished"))?;
+    Ok((board, tile, session.round()))
+}
+
+impl Registry {
+    fn session(&self, id: &str) -> Result<(u64, Arc<Mutex<Session>>), Reply> {
+        let not_found = || error(404, format!("no game {}", id));
+        let id = id.parse::<u64>().map_err(|_| not_found())?;
+        let sessions = self.sessions.lock().unwrap();
+        let session = sessions.get(&id).ok_or_else(not_found)?;
+        Ok((id, session.clone()))
+    }
+
+    fn create(&self, body: &Value) -> Result<Reply, Reply> {
+        let names: Vec<String> = match &body["players"] {
+            Value::Null => vec!["player".to_string()],
+            Value::Array(names) => names
+                .iter()
+                .map(|name| name.as_str().map(|name| name.to_string()))
+                .collect::<Option<_>>()
+                .ok_or_else(|| bad_request("players must be names".to_string()))?,
+            _ => return Err(bad_request("players must be a list".to_string())),
+        };
+        let seed = match &body["seed"] {
+            Value::Null => rand::random(),
+            seed => seed
+                .as_u64()
+                .ok_or_else(|| bad_request("invalid seed".to_string()))?,
+        };
+        let rules = match &body["rules"] {
+            Value::Null => RuleSet::default(),
+            rules => serde_json::from_value(rules.clone())
+                .map_err(|e| bad_request(format!("invalid rules: {}", e)))?,
+        };
+        let session = Session::with_rules(&names, rules, seed).map_err(bad_request)?;
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let body = state(id, &session);
+        self.sessions
+            .lock()
+            .unwrap()
+            .insert(id, Arc::new(Mutex::new(session)));
+        reply(201, body)
+    }
+
+    fn route(&self, method: &Method, url: &str, body: &str) -> Result<Reply, Reply> {
+        let path = url.split('?').next().unwrap_or("");
+        let parts: Vec<&str> = path.split('/').filter(|p| !p.is_empty()).collect();
+        match (method, &parts[..]) {
+            (Method::Post, ["games"]) => self.create(&body_json(body)?),
+            (Method::Get, ["games"]) => {
+                let mut ids: Vec<u64> = self.sessions.lock().unwrap().keys().copied().collect();
+                ids.sort();
+                reply(200, json!({ "games": ids }))
+            }
+            (Method::Get, ["games", id]) => {
+                let (id, session) = self.session(id)?;
+                let session = session.lock().unwrap();
+                reply(200, state(id, &session))
+            }
+            (Method::Delete, ["games", id]) => {
+                let (id, _) = self.session(id)?;
+                self.sessions.lock().unwrap().remove(&id);
+                reply(200, json!({ "deleted": id }))
+            }
+            (Method::Post, ["games", id, "place"]) => {
+                let body = body_json(body)?;
+                let player = string_field(&body, "player")?;
+                let (id, session) = self.session(id)?;
+                let mut session = session.lock().unwrap();
+                let field = field(&body["field"], session.rules())?;
+                session.place(player, field).map_err(|e| error(409, e))?;
+                reply(200, state(id, &session))
+            }
+            (Method::Post, ["games", id, "ai-move"]) => {
+                let body = body_json(body)?;
+                let player = string_field(&body, "player")?;
+                let depth = depth(&body["depth"])?;
+                let (id, session) = self.session(id)?;
+                let (board, tile, round) = position(&session, player)?;
+                let field =
+                    AI::best_field(&board, tile, depth).map_err(|_| error(409, "no field left"))?;
+                let mut session = session.lock().unwrap();
+                if session.round() != round {
+                    return Err(error(409, "the round ended during the search"));
+                }
+                session.place(player, field).map_err(|e| error(409, e))?;
+                reply(200, state(id, &session))
+            }
+            (Method::Get, ["games", id, "hint"]) => {
+                let query = query(url)?;
+                let player = query
+                    .get("player")
+                    .ok_or_else(|| bad_request("missing player".to_string()))?;
+                let depth = match query.get("depth") {
+                    Some(text) => depth(&json!(text.parse::<i64>().unwrap_or(-1)))?,
+                    None => DEFAULT_DEPTH,
+                };
+                let (_, session) = self.session(id)?;
+                let (board, tile, _) = position(&session, player)?;
+                let field =
+                    AI::best_field(&board, tile, depth).map_err(|_| error(409, "no field left"))?;
+                reply(
+                    200,
+                    json!({ "player": player, "tile": to_json(&tile), "field": to_json(&field) }),
+                )
+            }
+            (Method::Get, ["games", id, "lines"]) => {
+                let (_, session) = self.session(id)?;
+                let session = session.lock().unwrap();
+                let players: Vec<Value> = session
+                    .players
+                    .iter()
+                    .map(|p| {
+                        json!({
+                            "name": p.name,
+                            "score": p.game.board.score(),
+                            "lines": lines(&p.game.board),
+                        })
+                    })
+                    .collect();
+                reply(
+                    200,
+                    json!({ "finished": session.finished(), "players": players }),
+                )
+            }
+            _ => Err(error(404, format!("no route for {} {}", method, path))),
+        }
+    }
+
+    fn handle(&self, mut request: Request) {
+        let mut body = String::new();
+        let reply = match request.as_reader().read_to_string(&mut body) {
+            Ok(_) => self.route(request.method(), request.url(), &body),
+            Err(_) => Err(bad_request("cannot read the body".to_string())),
+        };
+        let reply = reply.unwrap_or_else(|e| e);
+        let header = Header::from_bytes("Content-Type", "application/json").unwrap();
+        let response = Response::from_string(reply.body.to_string())
+            .with_status_code(reply.status)
+            .with_header(header);
+        let _ = request.respond(response);
+    }
+}
+
+impl Server {
+    // e.g. "127.0.0.1:8080", port 0 picks a free port
+    pub fn bind(address: &str) -> Result<Server, String> {
+        let http = tiny_http::Server::http(address).map_err(|e| e.to_string())?;
+        Ok(Server {
+            http,
+            registry: Registry {
+                next_id: AtomicU64::new(1),
+                sessions: Mutex::new(HashMap::new()),
+            },
+        })
+    }
+
+    pub fn port(&self) -> Option<u16> {
+        self.http
+            .server_addr()
+            .to_ip()
+            .map(|address| address.port())
+    }
+
+    pub fn run(&self) {
+        thread::scope(|scope| {
+            for _ in 0..WORKERS {
+                scope.spawn(|| {
+                    for request in self.http.incoming_requests() {
+                        self.registry.handle(request);
+                    }
+                });
+            }
+        });
+    }
+}
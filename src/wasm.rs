@@ -0,0 +1,231 @@
+use crate::ai::AI;
+use crate::record::parse_tile;
+use crate::render::Style;
+use crate::rules::RuleSet;
+use crate::tile::Tile;
+use crate::{Board, Coordinate, Field, Game};
+use wasm_bindgen::prelude::*;
+
+// JavaScript bindings for wasm32-unknown-unknown, behind the `wasm` feature:
+//
+//     wasm-pack build crates/cdylib --target web -- --no-default-features --features wasm
+//
+// There is no random source in the browser build, the page passes the seed,
+// e.g. from `crypto.getRandomValues`. Tiles are arrays [top, left, right],
+// fields are a column and a row from 1 to 5. Errors are thrown as strings.
+// Only the classic game is covered, tiles and fields of other rule sets are
+// rejected.
+//
+//     const game = new Game(42n);
+//     game.placeTile(3, 3);
+//     const [column, row] = game.hint(1);
+
+fn field(column: i32, row: i32) -> Result<Field, String> {
+    let coordinate =
+        |n: i32| Coordinate::from_int(n).map_err(|_| format!("invalid field: {}, {}", column, row));
+    Field::new(coordinate(column)?, coordinate(row)?)
+}
+
+fn tile(top: i32, left: i32, right: i32) -> Result<Tile, String> {
+    // negative numbers wrap around and are rejected as well
+    RuleSet::default()
+        .tile(top as u32, left as u32, right as u32)
+        .map_err(|_| format!("invalid tile: {} {} {}", top, left, right))
+}
+
+fn numbers(tile: &Tile) -> Vec<u32> {
+    vec![tile.top, tile.left, tile.right]
+}
+
+fn coordinates(field: &Field) -> Vec<i32> {
+    vec![field.column as i32, field.row as i32]
+}
+
+// sorted [column, row, column, row, ...]
+fn flat_fields(fields: impl IntoIterator<Item = Field>) -> Vec<i32> {
+    let mut fields: Vec<Field> = fields.into_iter().collect();
+    fields.sort();
+    fields.iter().flat_map(coordinates).collect()
+}
+
+fn best_field(board: &Board, tile: Tile, depth: i32) -> Result<Vec<i32>, String> {
+    if depth < 0 {
+        return Err(format!("invalid depth: {}", depth));
+    }
+    AI::best_field(board, tile, depth)
+        .map(|field| coordinates(&field))
+        .map_err(|_| "no field for this tile".to_string())
+}
+
+#[wasm_bindgen(js_name = Board)]
+#[derive(Default)]
+pub struct WasmBoard {
+    board: Board,
+}
+
+#[wasm_bindgen(js_class = Board)]
+impl WasmBoard {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmBoard {
+        WasmBoard {
+            board: Board::new(),
+        }
+    }
+
+    #[wasm_bindgen(js_name = placeTile)]
+    pub fn place_tile(
+        &mut self,
+        column: i32,
+        row: i32,
+        top: i32,
+        left: i32,
+        right: i32,
+    ) -> Result<(), String> {
+        let field = field(column, row)?;
+        let tile = tile(top, left, right)?;
+        self.board
+            .place_tile(field, tile)
+            .map_err(|_| format!("cannot place {} on {}", tile, field))
+    }
+
+    #[wasm_bindgen(js_name = tileAt)]
+    pub fn tile_at(&self, column: i32, row: i32) -> Result<Option<Vec<u32>>, String> {
+        let field = field(column, row)?;
+        Ok(self
+            .board
+            .tiles
+            .get(&field)
+            .copied()
+            .flatten()
+            .as_ref()
+            .map(numbers))
+    }
+
+    #[wasm_bindgen(js_name = emptyFields)]
+    pub fn empty_fields(&self) -> Vec<i32> {
+        flat_fields(self.board.empty_fields())
+    }
+
+    #[wasm_bindgen(js_name = isFull)]
+    pub fn is_full(&self) -> bool {
+        self.board.is_full()
+    }
+
+    pub fn score(&self) -> u32 {
+        self.board.score()
+    }
+
+    #[wasm_bindgen(js_name = maxScore)]
+    pub fn max_score(&self) -> u32 {
+        self.board.max_score()
+    }
+
+    // [column, row] the AI would place the tile on
+    #[wasm_bindgen(js_name = bestField)]
+    pub fn best_field(
+        &self,
+        top: i32,
+        left: i32,
+        right: i32,
+        depth: i32,
+    ) -> Result<Vec<i32>, String> {
+        best_field(&self.board, tile(top, left, right)?, depth)
+    }
+
+    pub fn render(&self) -> String {
+        self.board.view(Style::Large).to_string()
+    }
+}
+
+#[wasm_bindgen(js_name = Game)]
+pub struct WasmGame {
+    game: Game,
+}
+
+#[wasm_bindgen(js_class = Game)]
+impl WasmGame {
+    // games with the same seed draw the same tiles
+    #[wasm_bindgen(constructor)]
+    pub fn new(seed: u64) -> WasmGame {
+        WasmGame {
+            game: Game::with_seed(seed),
+        }
+    }
+
+    pub fn load(text: &str) -> Result<WasmGame, String> {
+        Game::load(text).map(|game| WasmGame { game })
+    }
+
+    pub fn save(&self) -> String {
+        self.game.save()
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.game.seed()
+    }
+
+    // undefined once the game is finished
+    #[wasm_bindgen(js_name = currentTile)]
+    pub fn current_tile(&self) -> Option<Vec<u32>> {
+        if self.game.finished() {
+            return None;
+        }
+        self.game.current_tile.as_ref().map(numbers)
+    }
+
+    #[wasm_bindgen(js_name = placeTile)]
+    pub fn place_tile(&mut self, column: i32, row: i32) -> Result<(), String> {
+        let field = field(column, row)?;
+        self.game.check_placement(&field)?;
+        self.game
+            .place_tile(field)
+            .map_err(|_| format!("cannot place on {}", field))
+    }
+
+    // numbers of the tiles not on the board yet, three per tile
+    #[wasm_bindgen(js_name = remainingTiles)]
+    pub fn remaining_tiles(&self) -> Vec<u32> {
+        let mut tiles: Vec<Tile> = self.game.board.remaining_tiles().into_iter().collect();
+        tiles.sort();
+        tiles.iter().flat_map(numbers).collect()
+    }
+
+    // the board as a separate object, changes to it do not touch the game
+    pub fn board(&self) -> WasmBoard {
+        WasmBoard {
+            board: self.game.board.clone(),
+        }
+    }
+
+    #[wasm_bindgen(js_name = moveCount)]
+    pub fn move_count(&self) -> usize {
+        self.game.moves().len()
+    }
+
+    pub fn finished(&self) -> bool {
+        self.game.finished()
+    }
+
+    pub fn score(&self) -> u32 {
+        self.game.board.score()
+    }
+
+    // [column, row] the AI would place the current tile on
+    pub fn hint(&self, depth: i32) -> Result<Vec<i32>, String> {
+        match (self.game.finished(), self.game.current_tile) {
+            (false, Some(tile)) => best_field(&self.game.board, tile, depth),
+            _ => Err("the game is finished".to_string()),
+        }
+    }
+}
+
+// numbers of a tile from its record notation, e.g. "973"
+#[wasm_bindgen(js_name = parseTile)]
+pub fn parse_tile_numbers(text: &str) -> Result<Vec<u32>, String> {
+    parse_tile(text).map(|tile| numbers(&tile))
+}
+
+#[wasm_bindgen(js_name = parseField)]
+pub fn parse_field(text: &str) -> Result<Vec<i32>, String> {
+    text.parse::<Field>().map(|field| coordinates(&field))
+}
@@ -1,69 +1,42 @@
+#![cfg(feature = "std")]
+
 use ai::AI;
 use takeiteasy::*;
 
 fn get_full_board() -> Board {
-
-    let mut board = Board::new();
-    assert!(board.place_tile(field!(1, 1), tile!(9, 7, 3)).is_ok());
-    assert!(board.place_tile(field!(1, 2), tile!(9, 2, 4)).is_ok());
-    assert!(board.place_tile(field!(1, 3), tile!(9, 2, 3)).is_ok());
-
-    assert!(board.place_tile(field!(2, 1), tile!(5, 6, 8)).is_ok());
-    assert!(board.place_tile(field!(2, 2), tile!(5, 2, 8)).is_ok());
-    assert!(board.place_tile(field!(2, 3), tile!(1, 2, 4)).is_ok());
-    assert!(board.place_tile(field!(2, 4), tile!(5, 6, 3)).is_ok());
-
-    assert!(board.place_tile(field!(3, 1), tile!(1, 6, 3)).is_ok());
-    assert!(board.place_tile(field!(3, 2), tile!(1, 2, 8)).is_ok());
-    assert!(board.place_tile(field!(3, 3), tile!(9, 6, 4)).is_ok());
-    assert!(board.place_tile(field!(3, 4), tile!(1, 7, 4)).is_ok());
-    assert!(board.place_tile(field!(3, 5), tile!(1, 7, 3)).is_ok());
-
-    assert!(board.place_tile(field!(4, 1), tile!(1, 2, 3)).is_ok());
-    assert!(board.place_tile(field!(4, 2), tile!(1, 6, 8)).is_ok());
-    assert!(board.place_tile(field!(4, 3), tile!(1, 7, 8)).is_ok());
-    assert!(board.place_tile(field!(4, 4), tile!(5, 7, 4)).is_ok());
-
-    assert!(board.place_tile(field!(5, 1), tile!(5, 2, 3)).is_ok());
-    assert!(board.place_tile(field!(5, 2), tile!(5, 7, 8)).is_ok());
+    let mut board = get_almost_full_board();
     assert!(board.place_tile(field!(5, 3), tile!(5, 7, 3)).is_ok());
-
     board
 }
 
 fn get_almost_full_board() -> Board {
-
     // tile on field 5,3 is missing
-    let mut board = Board::new();
-    assert!(board.place_tile(field!(1, 1), tile!(9, 7, 3)).is_ok());
-    assert!(board.place_tile(field!(1, 2), tile!(9, 2, 4)).is_ok());
-    assert!(board.place_tile(field!(1, 3), tile!(9, 2, 3)).is_ok());
-
-    assert!(board.place_tile(field!(2, 1), tile!(5, 6, 8)).is_ok());
-    assert!(board.place_tile(field!(2, 2), tile!(5, 2, 8)).is_ok());
-    assert!(board.place_tile(field!(2, 3), tile!(1, 2, 4)).is_ok());
-    assert!(board.place_tile(field!(2, 4), tile!(5, 6, 3)).is_ok());
-
-    assert!(board.place_tile(field!(3, 1), tile!(1, 6, 3)).is_ok());
-    assert!(board.place_tile(field!(3, 2), tile!(1, 2, 8)).is_ok());
-    assert!(board.place_tile(field!(3, 3), tile!(9, 6, 4)).is_ok());
-    assert!(board.place_tile(field!(3, 4), tile!(1, 7, 4)).is_ok());
-    assert!(board.place_tile(field!(3, 5), tile!(1, 7, 3)).is_ok());
-
-    assert!(board.place_tile(field!(4, 1), tile!(1, 2, 3)).is_ok());
-    assert!(board.place_tile(field!(4, 2), tile!(1, 6, 8)).is_ok());
-    assert!(board.place_tile(field!(4, 3), tile!(1, 7, 8)).is_ok());
-    assert!(board.place_tile(field!(4, 4), tile!(5, 7, 4)).is_ok());
-
-    assert!(board.place_tile(field!(5, 1), tile!(5, 2, 3)).is_ok());
-    assert!(board.place_tile(field!(5, 2), tile!(5, 7, 8)).is_ok());
-    // assert!(board.place_tile(field!(5, 3), tile!(5, 7, 3)).is_ok());
-
-    board
+    Board::from_ascii(
+        r"                                _______
+                               /       \
+                       _______/    1    \_______
+                      /       \  6   3  /       \
+              _______/    5    \_______/    1    \_______
+             /       \  6   8  /       \  2   3  /       \
+            /    9    \_______/    1    \_______/    5    \
+            \  7   3  /       \  2   8  /       \  2   3  /
+             \_______/    5    \_______/    1    \_______/
+             /       \  2   8  /       \  6   8  /       \
+            /    9    \_______/    9    \_______/    5    \
+            \  2   4  /       \  6   4  /       \  7   8  /
+             \_______/    1    \_______/    1    \_______/
+             /       \  2   4  /       \  7   8  /       \
+            /    9    \_______/    1    \_______/         \
+            \  2   3  /       \  7   4  /       \         /
+             \_______/    5    \_______/    5    \_______/
+                     \  6   3  /       \  7   4  /
+                      \_______/    1    \_______/
+                              \  7   3  /
+                               \_______/",
+    )
+    .unwrap()
 }
 
-
-
 #[test]
 fn test_eval_empty_board() {
     let empty_board = Board::new();
@@ -101,7 +74,29 @@ fn test_eval_almost_full_board() {
     assert!(diff_percent < tolerance_percent);
 }
 
+#[cfg(feature = "entropy")]
 #[test]
 fn test_ai_full_game() {
     assert_ne!(0, AI::play_game(0, true));
 }
+
+#[test]
+fn test_best_field() {
+    let board = get_almost_full_board();
+    assert_eq!(Ok(field!(5, 3)), AI::best_field(&board, tile!(5, 7, 3), 1));
+    // full board or tile already placed
+    assert_eq!(
+        Err(()),
+        AI::best_field(&get_full_board(), tile!(9, 7, 8), 1)
+    );
+    assert_eq!(Err(()), AI::best_field(&board, tile!(1, 6, 3), 0));
+}
+
+#[test]
+fn test_negative_depth() {
+    let board = Board::new();
+    assert_eq!(Err(()), AI::estimated_score(&board, -1));
+    assert_eq!(None, AI::estimated_score_until(&board, -1, &|| false));
+    assert_eq!(Err(()), AI::best_field(&board, tile!(1, 2, 3), -1));
+    assert!(AI::best_field(&board, tile!(1, 2, 3), 0).is_ok());
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "std")]
+
+use record::ParseError;
+use takeiteasy::*;
+
+fn get_board() -> Board {
+    let mut board = Board::new();
+    assert!(board.place_tile(field!(1, 1), tile!(9, 7, 3)).is_ok());
+    assert!(board.place_tile(field!(2, 4), tile!(5, 6, 3)).is_ok());
+    assert!(board.place_tile(field!(3, 1), tile!(1, 6, 3)).is_ok());
+    assert!(board.place_tile(field!(3, 5), tile!(1, 7, 4)).is_ok());
+    assert!(board.place_tile(field!(5, 3), tile!(5, 7, 8)).is_ok());
+    board
+}
+
+#[test]
+fn test_read_printed_board() {
+    let board = get_board();
+    let parsed = Board::from_ascii(&board.to_string()).unwrap();
+    assert_eq!(board.tiles, parsed.tiles);
+}
+
+#[test]
+fn test_read_empty_and_full_board() {
+    assert!(Board::from_ascii(&Board::new().to_string())
+        .unwrap()
+        .tiles
+        .is_empty());
+
+    let mut board = Board::new();
+    let mut tiles: Vec<_> = TileReservoir::all_tiles().into_iter().collect();
+    tiles.sort();
+    for (field, tile) in Board::all_fields().into_iter().zip(tiles) {
+        assert!(board.place_tile(field, tile).is_ok());
+    }
+    let parsed = Board::from_ascii(&board.to_string()).unwrap();
+    assert_eq!(board.tiles, parsed.tiles);
+    assert_eq!(board.score(), parsed.score());
+}
+
+#[test]
+fn test_read_shifted_board() {
+    // pasted without the leading newline and with the indentation removed
+    let board = get_board();
+    let text: Vec<String> = board
+        .to_string()
+        .lines()
+        .skip(1)
+        .map(|line| line.chars().skip(10).collect())
+        .collect();
+    let parsed = Board::from_ascii(&text.join("\n")).unwrap();
+    assert_eq!(board.tiles, parsed.tiles);
+}
+
+#[test]
+fn test_read_errors() {
+    let text = get_board().to_string();
+    let lines: Vec<&str> = text.lines().collect();
+    let error = |line, column, message: &str| ParseError {
+        line,
+        column,
+        message: message.to_string(),
+    };
+
+    // top number of field 1, 1 is on line 8, column 18
+    let replace = |line: usize, column: usize, c: char| {
+        let mut lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+        let mut chars: Vec<char> = lines[line - 1].chars().collect();
+        chars[column - 1] = c;
+        lines[line - 1] = chars.into_iter().collect();
+        lines.join("\n")
+    };
+    assert_eq!(
+        Err(error(8, 18, "invalid top number: 4")),
+        Board::from_ascii(&replace(8, 18, '4'))
+    );
+    assert_eq!(
+        Err(error(8, 18, "unexpected 'x'")),
+        Board::from_ascii(&replace(8, 18, 'x'))
+    );
+    assert_eq!(
+        Err(error(8, 18, "incomplete tile on Field(1 1)")),
+        Board::from_ascii(&replace(8, 18, ' '))
+    );
+    assert_eq!(
+        Err(error(1, 1, "no board found")),
+        Board::from_ascii("nothing here")
+    );
+    assert_eq!(
+        Err(error(11, 1, "board ends early")),
+        Board::from_ascii(&lines[..10].join("\n")).map(|b| b.tiles.len())
+    );
+}
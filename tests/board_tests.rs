@@ -110,6 +110,7 @@ fn test_place_tile_on_invalid_field_on_new_board() {
     assert!(board.place_tile_on_new_board(field!(1, 5), tile!(1, 2, 3)).is_err());
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn test_print_empty_board() {
     let expected = r"
@@ -139,6 +140,7 @@ fn test_print_empty_board() {
     assert_eq!(expected, format!("{}", board));
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn test_print_single_tile() {
     let expected = r"
@@ -169,6 +171,7 @@ fn test_print_single_tile() {
     assert_eq!(expected, format!("{}", board));
 }
 
+#[cfg(feature = "entropy")]
 #[test]
 fn test_remaining_tiles_len() {
     let mut tiles = TileReservoir::new();
@@ -229,6 +232,7 @@ fn test_remaining_fields_on_full_board() {
     assert!(board.is_full());
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn test_print_full_board() {
     let expected = r"
@@ -0,0 +1,108 @@
+/* Exercises the C API, run by crates/cdylib/tests/ffi_tests.rs. Prints "ok" on success. */
+
+#include <stdio.h>
+
+#include "takeiteasy.h"
+
+#define CHECK(condition)                                                   \
+    do {                                                                   \
+        if (!(condition)) {                                                \
+            fprintf(stderr, "%s:%d: %s\n", __FILE__, __LINE__, #condition); \
+            return 1;                                                      \
+        }                                                                  \
+    } while (0)
+
+static int test_board(void) {
+    TieBoard *board = tie_board_new();
+    TieField corner = {1, 1};
+    TieTile tile = {9, 7, 3};
+    TieTile found;
+    bool placed;
+    uint32_t score;
+    size_t count;
+    TieLine lines[15];
+    TieTile remaining[27];
+    TieField best;
+
+    CHECK(tie_board_place_tile(board, corner, tile) == TIE_STATUS_OK);
+    CHECK(tie_board_place_tile(board, corner, (TieTile){5, 2, 8}) == TIE_STATUS_CANNOT_PLACE);
+    CHECK(tie_board_place_tile(board, (TieField){1, 5}, tile) == TIE_STATUS_INVALID_FIELD);
+    CHECK(tie_board_place_tile(board, (TieField){1, 2}, (TieTile){9, 7, 5}) == TIE_STATUS_INVALID_TILE);
+    /* a field and a tile of variants, the C API is classic only */
+    CHECK(tie_board_place_tile(board, (TieField){7, 1}, tile) == TIE_STATUS_INVALID_FIELD);
+    CHECK(tie_board_place_tile(board, (TieField){1, 2}, (TieTile){6, 9, 3}) == TIE_STATUS_INVALID_TILE);
+    CHECK(tie_board_place_tile(NULL, corner, tile) == TIE_STATUS_NULL_POINTER);
+
+    CHECK(tie_board_tile_at(board, corner, &found, &placed) == TIE_STATUS_OK);
+    CHECK(placed && found.top == 9 && found.left == 7 && found.right == 3);
+    CHECK(tie_board_tile_at(board, (TieField){3, 3}, &found, &placed) == TIE_STATUS_OK);
+    CHECK(!placed);
+
+    CHECK(tie_board_score(board, &score) == TIE_STATUS_OK);
+    CHECK(score == 0);
+    CHECK(tie_board_score(board, NULL) == TIE_STATUS_NULL_POINTER);
+    CHECK(tie_board_max_score(board, &score) == TIE_STATUS_OK);
+    CHECK(score > 0);
+
+    CHECK(tie_board_lines(board, lines, 2, &count) == TIE_STATUS_BUFFER_TOO_SMALL);
+    CHECK(count == 15);
+    CHECK(tie_board_lines(board, lines, 15, &count) == TIE_STATUS_OK);
+    CHECK(lines[0].direction >= 0 && lines[0].direction <= 2);
+    CHECK(lines[0].length >= 3 && lines[0].length <= 5);
+
+    CHECK(tie_board_remaining_tiles(board, remaining, 27, &count) == TIE_STATUS_OK);
+    CHECK(count == 26);
+
+    CHECK(tie_board_best_field(board, (TieTile){9, 7, 4}, 0, &best) == TIE_STATUS_OK);
+    CHECK(!(best.column == 1 && best.row == 1));
+    CHECK(tie_board_best_field(board, tile, 0, &best) == TIE_STATUS_CANNOT_PLACE);
+    CHECK(tie_board_best_field(board, (TieTile){9, 7, 4}, -1, &best) == TIE_STATUS_INVALID_DEPTH);
+
+    tie_board_free(board);
+    tie_board_free(NULL);
+    return 0;
+}
+
+static int test_game(void) {
+    TieGame *game = tie_game_new(42);
+    TieGame *same = tie_game_new(42);
+    TieTile tile, other;
+    TieField field;
+    TieBoard *board;
+    bool finished = false;
+    uint32_t score, board_score;
+    int moves = 0;
+
+    CHECK(tie_game_current_tile(game, &tile) == TIE_STATUS_OK);
+    CHECK(tie_game_current_tile(same, &other) == TIE_STATUS_OK);
+    CHECK(tile.top == other.top && tile.left == other.left && tile.right == other.right);
+
+    while (!finished) {
+        CHECK(tie_game_best_field(game, 0, &field) == TIE_STATUS_OK);
+        CHECK(tie_game_place_tile(game, field) == TIE_STATUS_OK);
+        CHECK(tie_game_place_tile(game, field) != TIE_STATUS_OK);
+        CHECK(tie_game_finished(game, &finished) == TIE_STATUS_OK);
+        moves++;
+    }
+    CHECK(moves == 19);
+    CHECK(tie_game_current_tile(game, &tile) == TIE_STATUS_GAME_FINISHED);
+    CHECK(tie_game_best_field(game, 0, &field) == TIE_STATUS_GAME_FINISHED);
+
+    CHECK(tie_game_score(game, &score) == TIE_STATUS_OK);
+    CHECK(tie_game_board(game, &board) == TIE_STATUS_OK);
+    CHECK(tie_board_score(board, &board_score) == TIE_STATUS_OK);
+    CHECK(score == board_score);
+
+    tie_board_free(board);
+    tie_game_free(same);
+    tie_game_free(game);
+    return 0;
+}
+
+int main(void) {
+    if (test_board() != 0 || test_game() != 0) {
+        return 1;
+    }
+    printf("ok\n");
+    return 0;
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "std")]
+
+use color::Emphasis;
+use render::Style;
+use std::ffi::OsStr;
+use takeiteasy::*;
+
+fn strip_escapes(text: &str) -> String {
+    let mut result = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            for c in chars.by_ref() {
+                if c == 'm' {
+                    break;
+                }
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn get_board() -> Board {
+    // a completed vertical line of 9 in column 1 and a broken one in column 2
+    let mut board = Board::new();
+    assert!(board.place_tile(field!(1, 1), tile!(9, 7, 3)).is_ok());
+    assert!(board.place_tile(field!(1, 2), tile!(9, 6, 4)).is_ok());
+    assert!(board.place_tile(field!(1, 3), tile!(9, 2, 8)).is_ok());
+    assert!(board.place_tile(field!(2, 1), tile!(1, 6, 3)).is_ok());
+    assert!(board.place_tile(field!(2, 2), tile!(5, 2, 8)).is_ok());
+    board
+}
+
+#[test]
+fn test_paint() {
+    assert_eq!(
+        "\x1b[38;2;250;215;40m9\x1b[0m",
+        color::paint("9", 9, Emphasis::Normal)
+    );
+    assert_eq!(
+        "\x1b[1;38;2;40;190;180m5\x1b[0m",
+        color::paint("5", 5, Emphasis::Bold)
+    );
+    assert!(color::paint("1", 1, Emphasis::Dim).starts_with("\x1b[2;"));
+}
+
+#[test]
+fn test_colored_tile() {
+    let tile = tile!(9, 7, 3);
+    assert_ne!(tile.to_string(), tile.colored());
+    assert_eq!(tile.to_string(), strip_escapes(&tile.colored()));
+}
+
+#[test]
+fn test_colored_board_has_same_layout() {
+    let board = get_board();
+    for style in [Style::Large, Style::Compact, Style::Unicode, Style::Summary] {
+        let plain = board.view(style).to_string();
+        let colored = board.view(style).colored(true).to_string();
+        assert_ne!(plain, colored);
+        assert_eq!(plain, strip_escapes(&colored));
+        assert_eq!(plain, board.view(style).colored(false).to_string());
+    }
+}
+
+#[test]
+fn test_line_emphasis() {
+    let colored = get_board().view(Style::Compact).colored(true).to_string();
+    // completed line of 9
+    assert_eq!(
+        3,
+        colored
+            .matches(&color::paint("9", 9, Emphasis::Bold))
+            .count()
+    );
+    // broken line of 1 and 5
+    assert!(colored.contains(&color::paint("1", 1, Emphasis::Dim)));
+    assert!(colored.contains(&color::paint("5", 5, Emphasis::Dim)));
+    // some numbers are on lines that are still open
+    assert!(colored.contains("\x1b[38;2;"));
+}
+
+#[test]
+fn test_no_color() {
+    let set = Some(OsStr::new("1"));
+    assert!(color::color_choice(None, None, true));
+    assert!(!color::color_choice(None, set, true));
+    assert!(!color::color_choice(None, None, false));
+    // empty counts as not set
+    assert!(color::color_choice(None, Some(OsStr::new("")), true));
+    // the flag wins
+    assert!(color::color_choice(Some(true), set, false));
+    assert!(!color::color_choice(Some(false), None, true));
+}
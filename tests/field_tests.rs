@@ -61,3 +61,35 @@ fn test_field_construction() {
     check_new_invalid_field!(5, 4, "invalid coordinates: 5, 4");
     check_new_invalid_field!(5, 5, "invalid coordinates: 5, 5");
 }
+
+#[test]
+fn test_field_ids() {
+    assert_eq!('a', field!(1, 1).id());
+    assert_eq!('h', field!(3, 1).id());
+    assert_eq!('s', field!(5, 3).id());
+    for field in Board::all_fields() {
+        assert_eq!(Ok(field), Field::from_id(field.id()));
+    }
+    assert_eq!(Ok(field!(3, 2)), Field::from_id('I'));
+    assert_eq!(Err(()), Field::from_id('t'));
+    assert_eq!(Err(()), Field::from_id('1'));
+}
+
+#[test]
+fn test_parse_field() {
+    for text in ["3 2", "3,2", " 3 , 2 ", "c3r2", "C3R2", "i", "I"] {
+        assert_eq!(Ok(field!(3, 2)), text.parse::<Field>(), "{}", text);
+    }
+    assert_eq!(Ok(field!(3, 3)), "center".parse::<Field>());
+    assert_eq!(Ok(field!(1, 1)), "top-left".parse::<Field>());
+    assert_eq!(Ok(field!(5, 3)), "Bottom Right".parse::<Field>());
+    assert_eq!(Ok(field!(3, 5)), "bottom".parse::<Field>());
+
+    assert_eq!(
+        Err("invalid coordinates: 1, 5".to_string()),
+        "1 5".parse::<Field>()
+    );
+    for text in ["", "t", "3", "3 2 1", "0 1", "c6r1", "middle"] {
+        assert!(text.parse::<Field>().is_err(), "{}", text);
+    }
+}
@@ -1,5 +1,6 @@
 use takeiteasy::*;
 
+#[cfg(feature = "entropy")]
 #[test]
 fn test_start_to_finish() {
     let mut total_score = 0;
@@ -16,3 +17,17 @@ fn test_start_to_finish() {
     }
     assert!(total_score > 0);
 }
+
+#[test]
+fn test_seeded_games_draw_the_same_tiles() {
+    let play = |seed: u64| {
+        let mut game = Game::with_seed(seed);
+        for field in Board::all_fields() {
+            assert!(game.place_tile(field).is_ok());
+        }
+        assert_eq!(seed, game.seed());
+        game.moves().to_vec()
+    };
+    assert_eq!(play(42), play(42));
+    assert_ne!(play(42), play(43));
+}
@@ -0,0 +1,121 @@
+use hex::Hex;
+use rules::RuleSet;
+use std::collections::BTreeSet;
+use takeiteasy::*;
+use tile::Direction;
+
+const DIRECTIONS: [Direction; 3] = [Direction::Top, Direction::Left, Direction::Right];
+
+fn rules(radius: u32) -> RuleSet {
+    RuleSet::with_radius(radius, &[1, 3, 5, 9], &[2, 4, 6, 7], &[3, 4, 8]).unwrap()
+}
+
+#[test]
+fn test_fields() {
+    assert_eq!(Hex::new(0, 0), Hex::from_field(&field!(3, 3), 2));
+    assert_eq!(Hex::new(-2, 0), Hex::from_field(&field!(1, 1), 2));
+    assert_eq!(Hex::new(2, -2), Hex::from_field(&field!(5, 1), 2));
+    assert_eq!(Hex::new(0, 1), Hex::from_field(&field!(2, 3), 1));
+    assert_eq!(Some(field!(4, 4)), Hex::CENTER.to_field(3));
+    assert_eq!(None, Hex::new(2, 1).to_field(2));
+    assert_eq!(None, Hex::new(3, 0).to_field(2));
+
+    for radius in 1..=3 {
+        let hexes: Vec<Hex> = hex::hexes(radius).collect();
+        assert_eq!(rules(radius).num_fields(), hexes.len());
+        for hex in hexes {
+            assert!(hex.length() <= radius as i32);
+            let field = hex.to_field(radius).unwrap();
+            assert_eq!(hex, Hex::from_field(&field, radius));
+        }
+    }
+}
+
+#[test]
+fn test_neighbors() {
+    let hex = Hex::new(1, -2);
+    for (i, neighbor) in hex.neighbors().iter().enumerate() {
+        assert_eq!(1, hex.distance(neighbor));
+        assert_eq!(hex + Hex::STEPS[i], *neighbor);
+    }
+    assert_eq!(3, Hex::new(-2, 0).distance(&Hex::new(1, -1)));
+    assert_eq!(4, Hex::new(-2, 0).distance(&Hex::new(2, 0)));
+    assert_eq!(2, Hex::new(1, -2).length());
+
+    // the corners of the board
+    for step in Hex::STEPS {
+        assert!((step * 2).to_field(2).is_some());
+        assert!((step * 3).to_field(2).is_none());
+    }
+    assert_eq!(Some(field!(5, 3)), (Hex::DOWN_RIGHT * 2).to_field(2));
+}
+
+#[test]
+fn test_lines() {
+    let hex = Hex::from_field(&field!(2, 3), 2);
+    assert_eq!(-1, hex.line(&Direction::Top));
+    assert_eq!(0, hex.line(&Direction::Left));
+    assert_eq!(1, hex.line(&Direction::Right));
+
+    for radius in 1..=3 {
+        let rules = rules(radius);
+        for direction in &DIRECTIONS {
+            for (i, line) in rules.lines(direction).iter().enumerate() {
+                let hexes: Vec<Hex> =
+                    hex::line(direction, i as i32 - radius as i32, radius).collect();
+                assert_eq!(line.len(), hexes.len());
+                for (hex, field) in hexes.iter().zip(line) {
+                    assert_eq!(*hex, rules.hex(field));
+                }
+                // one step apart from each other
+                for pair in hexes.windows(2) {
+                    assert_eq!(pair[1], pair[0] + Hex::step(direction));
+                }
+            }
+        }
+    }
+}
+
+fn line_sets(radius: u32, direction: &Direction, map: impl Fn(Hex) -> Hex) -> BTreeSet<Vec<Hex>> {
+    let radius_int = radius as i32;
+    (-radius_int..=radius_int)
+        .map(|line| {
+            let mut hexes: Vec<Hex> = hex::line(direction, line, radius).map(&map).collect();
+            hexes.sort();
+            hexes
+        })
+        .collect()
+}
+
+#[test]
+fn test_rotate_and_reflect() {
+    let hex = Hex::new(2, -1);
+    assert_eq!(Hex::new(1, 1), hex.rotate(1));
+    assert_eq!(Hex::new(-2, 1), hex.rotate(3));
+    assert_eq!(hex.rotate(-1), hex.rotate(5));
+    // the mirror image turns the other way
+    assert_eq!(hex.rotate(-1), hex.reflect().rotate(1).reflect());
+
+    for radius in 1..=3 {
+        for hex in hex::hexes(radius) {
+            assert_eq!(hex, hex.rotate(6));
+            assert_eq!(hex, hex.reflect().reflect());
+            assert_eq!(hex.length(), hex.rotate(1).length());
+            assert!(hex.reflect().to_field(radius).is_some());
+        }
+        for direction in &DIRECTIONS {
+            let lines = line_sets(radius, direction, |hex| hex);
+            // half a turn keeps the lines in their direction
+            assert_eq!(lines, line_sets(radius, direction, |hex| hex.rotate(3)));
+        }
+        // the mirror image swaps left and right lines
+        assert_eq!(
+            line_sets(radius, &Direction::Top, |hex| hex),
+            line_sets(radius, &Direction::Top, |hex| hex.reflect())
+        );
+        assert_eq!(
+            line_sets(radius, &Direction::Right, |hex| hex),
+            line_sets(radius, &Direction::Left, |hex| hex.reflect())
+        );
+    }
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "std")]
+
+use oracle::Oracle;
+use std::collections::HashSet;
+use takeiteasy::*;
+use tile::Tile;
+
+fn get_full_board() -> Board {
+    let mut board = Board::new();
+    assert!(board.place_tile(field!(1, 1), tile!(9, 7, 3)).is_ok());
+    assert!(board.place_tile(field!(1, 2), tile!(9, 2, 4)).is_ok());
+    assert!(board.place_tile(field!(1, 3), tile!(9, 2, 3)).is_ok());
+
+    assert!(board.place_tile(field!(2, 1), tile!(5, 6, 8)).is_ok());
+    assert!(board.place_tile(field!(2, 2), tile!(5, 2, 8)).is_ok());
+    assert!(board.place_tile(field!(2, 3), tile!(1, 2, 4)).is_ok());
+    assert!(board.place_tile(field!(2, 4), tile!(5, 6, 3)).is_ok());
+
+    assert!(board.place_tile(field!(3, 1), tile!(1, 6, 3)).is_ok());
+    assert!(board.place_tile(field!(3, 2), tile!(1, 2, 8)).is_ok());
+    assert!(board.place_tile(field!(3, 3), tile!(9, 6, 4)).is_ok());
+    assert!(board.place_tile(field!(3, 4), tile!(1, 7, 4)).is_ok());
+    assert!(board.place_tile(field!(3, 5), tile!(1, 7, 3)).is_ok());
+
+    assert!(board.place_tile(field!(4, 1), tile!(1, 2, 3)).is_ok());
+    assert!(board.place_tile(field!(4, 2), tile!(1, 6, 8)).is_ok());
+    assert!(board.place_tile(field!(4, 3), tile!(1, 7, 8)).is_ok());
+    assert!(board.place_tile(field!(4, 4), tile!(5, 7, 4)).is_ok());
+
+    assert!(board.place_tile(field!(5, 1), tile!(5, 2, 3)).is_ok());
+    assert!(board.place_tile(field!(5, 2), tile!(5, 7, 8)).is_ok());
+    assert!(board.place_tile(field!(5, 3), tile!(5, 7, 3)).is_ok());
+
+    board
+}
+
+#[test]
+fn test_oracle_uses_drawn_tiles() {
+    let tiles: Vec<Tile> = get_full_board()
+        .tiles
+        .values()
+        .map(|t| t.unwrap())
+        .collect();
+    let (board, score) = Oracle::best_board(&tiles).unwrap();
+
+    assert!(board.is_full());
+    assert_eq!(score, board.score());
+    let placed: HashSet<Tile> = board.tiles.values().map(|t| t.unwrap()).collect();
+    assert_eq!(placed, tiles.into_iter().collect());
+}
+
+#[test]
+fn test_oracle_beats_played_board() {
+    let played = get_full_board();
+    let tiles: Vec<Tile> = played.tiles.values().map(|t| t.unwrap()).collect();
+    let (_, score) = Oracle::best_board(&tiles).unwrap();
+    assert!(score >= played.score());
+}
+
+#[cfg(feature = "entropy")]
+#[test]
+fn test_oracle_beats_random_games() {
+    for _ in 0..3 {
+        let mut game = Game::new();
+        let mut drawn = Vec::new();
+        for field in Board::all_fields() {
+            drawn.push(game.current_tile.unwrap());
+            assert!(game.place_tile(field).is_ok());
+        }
+        let (board, score) = Oracle::best_board(&drawn).unwrap();
+        assert!(score >= game.board.score());
+        assert!(score <= board.max_score());
+    }
+}
+
+#[test]
+fn test_oracle_invalid_input() {
+    let tiles: Vec<Tile> = get_full_board()
+        .tiles
+        .values()
+        .map(|t| t.unwrap())
+        .collect();
+    assert_eq!(
+        Oracle::best_board(&tiles[..18]).unwrap_err(),
+        "expected 19 tiles, got 18"
+    );
+
+    let mut duplicated = tiles[..18].to_vec();
+    duplicated.push(tiles[0]);
+    assert_eq!(
+        Oracle::best_board(&duplicated).unwrap_err(),
+        "tiles must be distinct"
+    );
+}
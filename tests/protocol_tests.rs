@@ -0,0 +1,209 @@
+#![cfg(feature = "std")]
+
+use ai::AI;
+use std::io::{BufReader, Cursor, Read, Write};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use takeiteasy::*;
+
+fn run(script: &str) -> Vec<String> {
+    let mut output = Vec::new();
+    protocol::run(Cursor::new(script), &mut output).unwrap();
+    String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|l| l.to_string())
+        .collect()
+}
+
+// all fields but C5R3 filled
+const ALMOST_FULL: &str = "setboard 168 C1R1 164 C1R2 163 C1R3 128 C2R1 124 C2R2 123 C2R3 \
+    178 C2R4 174 C3R1 173 C3R2 568 C3R3 564 C3R4 563 C3R5 528 C4R1 524 C4R2 523 C4R3 \
+    578 C4R4 574 C5R1 573 C5R2";
+
+#[test]
+fn test_go_depth() {
+    let output = run(&format!("{}\ntile 968\ngo depth 1\n", ALMOST_FULL));
+    assert_eq!(3, output.len());
+    assert!(output[0].starts_with("info depth 0 field C5R3 ev "));
+    assert!(output[1].starts_with("info depth 1 field C5R3 ev "));
+    // only one field left, the estimate is the final score
+    let mut board = Board::new();
+    let words: Vec<&str> = ALMOST_FULL.split_whitespace().skip(1).collect();
+    for pair in words.chunks(2) {
+        let tile = record::parse_tile(pair[0]).unwrap();
+        let field = record::parse_field(pair[1]).unwrap();
+        board.place_tile(field, tile).unwrap();
+    }
+    board.place_tile(field!(5, 3), tile!(9, 6, 8)).unwrap();
+    assert_eq!(format!("bestmove C5R3 ev {}.0", board.score()), output[2]);
+}
+
+#[test]
+fn test_best_field_matches_ai() {
+    let output = run("setboard 973 C1R1 528 C3R3\ntile 974\ngo depth 0\n");
+    let mut board = Board::new();
+    board.place_tile(field!(1, 1), tile!(9, 7, 3)).unwrap();
+    board.place_tile(field!(3, 3), tile!(5, 2, 8)).unwrap();
+    let best = AI::best_field(&board, tile!(9, 7, 4), 0).unwrap();
+    assert!(output
+        .last()
+        .unwrap()
+        .starts_with(&format!("bestmove {}", record::format_field(&best))));
+}
+
+#[test]
+fn test_place_and_isready() {
+    let output = run(&format!(
+        "{}\ntile 968\nplace C5R3\ntile 968\nisready\nnewgame\ntile 968\nisready\n",
+        ALMOST_FULL
+    ));
+    assert_eq!(
+        vec!["error 968 is already on the board", "readyok", "readyok"],
+        output
+    );
+}
+
+#[test]
+fn test_movetime_and_quit() {
+    let output = run("tile 973\ngo movetime 0\nisready\nquit\ntile 123\n");
+    assert_eq!(3, output.len());
+    assert!(output[0].starts_with("info depth 0 field "));
+    assert!(output[1].starts_with("bestmove "));
+    assert_eq!("readyok", output[2]);
+}
+
+// the last depth with an info line, a search stops before depth 2 of an
+// empty board is done, a single field of it takes seconds
+fn last_depth(output: &[String]) -> i32 {
+    output
+        .iter()
+        .rev()
+        .filter(|line| line.contains(" field "))
+        .find_map(|line| line.split_whitespace().nth(2)?.parse().ok())
+        .unwrap()
+}
+
+#[test]
+fn test_movetime_is_honored() {
+    let output = run("tile 973\ngo movetime 300\n");
+    assert!(last_depth(&output) < 2);
+    assert!(output.last().unwrap().starts_with("bestmove "));
+}
+
+// output shared with `StopAfter`
+#[derive(Clone, Default)]
+struct SharedOutput(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedOutput {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl SharedOutput {
+    fn lines(&self) -> Vec<String> {
+        let output = self.0.lock().unwrap();
+        String::from_utf8_lossy(&output)
+            .lines()
+            .map(|l| l.to_string())
+            .collect()
+    }
+}
+
+// input that sends `stop` once a line starting with the prefix was written
+struct StopAfter {
+    output: SharedOutput,
+    prefix: &'static str,
+    sent: bool,
+}
+
+impl Read for StopAfter {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.sent {
+            return Ok(0);
+        }
+        while !self
+            .output
+            .lines()
+            .iter()
+            .any(|l| l.starts_with(self.prefix))
+        {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        self.sent = true;
+        buf[..5].copy_from_slice(b"stop\n");
+        Ok(5)
+    }
+}
+
+#[test]
+fn test_stop_within_a_field() {
+    let output = SharedOutput::default();
+    let stop = StopAfter {
+        output: output.clone(),
+        prefix: "info depth 1 field ",
+        sent: false,
+    };
+    let input = Cursor::new("tile 973\ngo depth 3\n").chain(stop);
+    protocol::run(BufReader::new(input), output.clone()).unwrap();
+    let output = output.lines();
+    assert_eq!(1, last_depth(&output));
+    assert!(output.last().unwrap().starts_with("bestmove "));
+}
+
+#[test]
+fn test_rules() {
+    let output = run(
+        "setboard 973 C7R1\nrules 1359 2467 348 r3\nsetboard 973 C7R1\n\
+        tile 974\ngo depth 0\nrules classic\nplace C7R1\nrules 12\n",
+    );
+    assert_eq!("error invalid field: C7R1", output[0]);
+    assert!(output[2].starts_with("bestmove "));
+    assert_eq!(
+        vec!["error no tile", "error invalid rule set: 12"],
+        output[3..]
+    );
+
+    let rules = rules::RuleSet::with_radius(1, &[1, 5, 9], &[2, 6, 7], &[3, 4, 8]).unwrap();
+    let mut output = Vec::new();
+    protocol::run_with(Cursor::new("tile 973\ngo depth 1\n"), &mut output, rules).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let best = output
+        .lines()
+        .last()
+        .unwrap()
+        .split_whitespace()
+        .nth(1)
+        .unwrap();
+    let best = record::parse_field_with(best, &rules).unwrap();
+    assert!(rules.fields().contains(&best));
+}
+
+#[test]
+fn test_errors() {
+    let output = run(
+        "foo\ngo depth 1\ntile 9\ntile 973 528\nsetboard 973\nsetboard 973 C1R1 973 C1R2\n\
+         place C1R1\ntile 973\ngo depth x\ngo\nplace C9R9\n",
+    );
+    assert_eq!(
+        vec![
+            "error unknown command: foo",
+            "error no tile",
+            "error invalid tile: 9",
+            "error tile needs one tile",
+            "error setboard needs pairs of tile and field",
+            "error cannot place 973 at C1R2",
+            "error no tile",
+            "error invalid depth: x",
+            "error go needs `depth <n>` or `movetime <ms>`",
+            "error invalid field: C9R9",
+        ],
+        output
+    );
+}
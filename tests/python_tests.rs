@@ -0,0 +1,135 @@
+#![cfg(feature = "python")]
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::wrap_pymodule;
+
+// runs the Python code with the module imported as `takeiteasy`
+fn run(code: &str) {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let globals = PyDict::new_bound(py);
+        let module = wrap_pymodule!(takeiteasy::python::takeiteasy)(py);
+        globals.set_item("takeiteasy", module).unwrap();
+        if let Err(e) = py.run_bound(code, Some(&globals), None) {
+            e.print(py);
+            panic!("python code failed");
+        }
+    });
+}
+
+#[test]
+fn test_tile_and_field() {
+    run(r#"
+from_numbers = takeiteasy.Tile(9, 7, 3)
+assert from_numbers == takeiteasy.Tile.parse("973")
+assert from_numbers.numbers() == (9, 7, 3)
+assert repr(from_numbers) == "Tile(9, 7, 3)"
+assert len({from_numbers, takeiteasy.Tile(9, 7, 3)}) == 1
+try:
+    takeiteasy.Tile(9, 9, 9)
+    assert False
+except ValueError:
+    pass
+# the tile and the field of variants, the module is classic only
+for make in [lambda: takeiteasy.Tile(6, 9, 3), lambda: takeiteasy.Field(7, 1)]:
+    try:
+        make()
+        assert False
+    except ValueError:
+        pass
+
+field = takeiteasy.Field(3, 2)
+assert (field.column, field.row) == (3, 2)
+assert field == takeiteasy.Field.parse("c3r2")
+assert takeiteasy.Field.parse("center") == takeiteasy.Field(3, 3)
+assert takeiteasy.Field.parse(field.id) == field
+try:
+    takeiteasy.Field(1, 5)
+    assert False
+except ValueError:
+    pass
+assert len(takeiteasy.fields()) == 19
+assert len(takeiteasy.tiles()) == 27
+"#);
+}
+
+#[test]
+fn test_board() {
+    run(r#"
+Tile, Field = takeiteasy.Tile, takeiteasy.Field
+board = takeiteasy.Board()
+for row, left in [(1, 7), (2, 6), (3, 2)]:
+    board.place_tile(Field(1, row), Tile(9, left, 3))
+try:
+    board.place_tile(Field(1, 1), Tile(5, 2, 8))
+    assert False
+except ValueError:
+    pass
+assert board.tile_at(Field(1, 2)) == Tile(9, 6, 3)
+assert board.tile_at(Field(3, 3)) is None
+assert board.score() == 27
+assert len(board.empty_fields()) == 16
+assert len(board.remaining_tiles()) == 24
+
+lines = board.lines()
+assert len(lines) == 15
+top = [l for l in lines if l["direction"] == "top" and Field(1, 1) in l["fields"]][0]
+assert top["score"] == 27 and not top["broken"]
+
+assert board.max_score() >= board.score()
+assert board.estimated_score(0) == board.max_score()
+evaluations = board.evaluate(Tile(5, 2, 8))
+assert len(evaluations) == 16
+best = board.best_field(Tile(5, 2, 8), 0)
+assert max(evaluations, key=lambda e: e[1])[1] == dict(evaluations)[best]
+assert "9" in str(board)
+"#);
+}
+
+#[test]
+fn test_game() {
+    run(r#"
+game = takeiteasy.Game(7)
+assert game.seed == 7
+first = game.current_tile
+assert first == takeiteasy.Game(7).current_tile
+while not game.finished():
+    game.place_tile(game.hint(0))
+assert game.current_tile is None
+assert len(game.moves) == 19
+assert game.moves[0][0] == first
+assert game.score() == game.board.score()
+try:
+    game.hint(0)
+    assert False
+except ValueError:
+    pass
+
+loaded = takeiteasy.Game.load(game.save())
+assert loaded.score() == game.score()
+"#);
+}
+
+#[test]
+fn test_simulate() {
+    run(r#"
+result = takeiteasy.simulate(20, depth=0, seed=3)
+assert result["seeds"] == list(range(3, 23))
+assert len(result["scores"]) == 20
+assert all(len(lines) == 15 for lines in result["lines"])
+assert [sum(lines) for lines in result["lines"]] == result["scores"]
+assert all(len(board) == 19 and all(len(t) == 3 for t in board) for board in result["boards"])
+assert takeiteasy.simulate(20, depth=0, seed=3) == result
+try:
+    takeiteasy.simulate(1, depth=-1)
+    assert False
+except ValueError as e:
+    assert str(e) == "invalid depth: -1"
+
+game = takeiteasy.Game(3)
+while not game.finished():
+    game.place_tile(game.hint(0))
+assert game.score() == result["scores"][0]
+"#);
+}
@@ -0,0 +1,209 @@
+#![cfg(feature = "std")]
+
+use ai::AI;
+use oracle::Oracle;
+use record::GameRecord;
+use rules::RuleSet;
+use solver::Solver;
+use takeiteasy::*;
+use tile::{Direction, Tile};
+
+fn small() -> RuleSet {
+    RuleSet::with_radius(1, &[1, 5, 9], &[2, 6, 7], &[3, 4, 8]).unwrap()
+}
+
+fn large() -> RuleSet {
+    RuleSet::with_radius(3, &[1, 3, 5, 9], &[2, 4, 6, 7], &[3, 4, 8]).unwrap()
+}
+
+fn play(rules: RuleSet, seed: u64) -> Game {
+    let mut game = Game::with_rules(rules, seed);
+    for field in rules.fields() {
+        assert!(game.place_tile(field).is_ok());
+    }
+    game
+}
+
+#[test]
+fn test_fields() {
+    assert_eq!(7, small().fields().len());
+    assert_eq!(19, RuleSet::default().fields().len());
+    assert_eq!(37, large().fields().len());
+    assert_eq!(Board::all_fields(), RuleSet::default().fields());
+
+    for rules in [small(), RuleSet::default(), large()] {
+        assert_eq!(rules.num_fields(), rules.fields().len());
+        // every field is on one line per direction
+        for direction in [Direction::Top, Direction::Left, Direction::Right] {
+            let lines = rules.lines(&direction);
+            assert_eq!(2 * rules.radius() as usize + 1, lines.len());
+            let mut fields: Vec<Field> = lines.concat();
+            fields.sort();
+            assert_eq!(rules.fields().into_iter().collect::<Vec<_>>(), fields);
+        }
+    }
+
+    assert!(small().field(Coordinate::C2, Coordinate::C3).is_ok());
+    assert_eq!(
+        Err("invalid coordinates: 1, 3".to_string()),
+        small().field(Coordinate::C1, Coordinate::C3)
+    );
+    assert!(large().field(Coordinate::C7, Coordinate::C4).is_ok());
+    assert!(large().field(Coordinate::C7, Coordinate::C5).is_err());
+}
+
+#[test]
+fn test_lines() {
+    assert_eq!(
+        vec![field!(2, 3), field!(3, 2)],
+        small().lines(&Direction::Left)[2]
+    );
+    assert_eq!(
+        vec![field!(1, 1), field!(2, 2), field!(3, 2)],
+        small().lines(&Direction::Right)[1]
+    );
+    assert_eq!(
+        vec![field!(4, 7), field!(5, 6), field!(6, 5), field!(7, 4)],
+        large().lines(&Direction::Left)[6]
+    );
+}
+
+#[test]
+fn test_radius_rules() {
+    assert_eq!(2, RuleSet::default().radius());
+    assert_eq!("159 267 348 r1", small().to_string());
+    assert_eq!(Ok(small()), "159 267 348 r1".parse());
+    assert_eq!(Ok(large()), large().to_string().parse());
+    assert!(!small().is_classic());
+    assert_eq!(48, large().tiles().len());
+
+    assert_eq!(
+        Err("invalid radius: 4".to_string()),
+        RuleSet::with_radius(4, &[1, 5, 9], &[2, 6, 7], &[3, 4, 8])
+    );
+    assert_eq!(
+        Err("27 tiles are too few for 37 fields".to_string()),
+        "159 267 348 r3".parse::<RuleSet>()
+    );
+    assert_eq!(
+        Err("8 tiles are too few for 19 fields".to_string()),
+        RuleSet::with_radius(2, &[1, 9], &[2, 7], &[3, 8])
+    );
+    assert!(RuleSet::with_radius(1, &[1, 9], &[2, 7], &[3, 8]).is_ok());
+    assert!("159 267 348 r".parse::<RuleSet>().is_err());
+}
+
+#[test]
+fn test_small_game() {
+    let game = play(small(), 5);
+    assert!(game.finished());
+    assert_eq!(7, game.moves().len());
+    assert!(game.board.is_full());
+
+    let record = GameRecord::from_game(&game);
+    assert_eq!(Some("159 267 348 r1"), record.tag("Ruleset"));
+    let parsed = GameRecord::parse(&record.to_string()).unwrap();
+    assert_eq!(game.board, parsed.to_board().unwrap());
+
+    // fields of the classic board that are not on the small one
+    let error = GameRecord::parse("[Ruleset \"159 267 348 r1\"]\n973 C3R3\n").unwrap_err();
+    assert_eq!("invalid coordinates: 3, 3", error.message);
+    assert!(Board::with_rules(small())
+        .place_tile(field!(4, 1), tile!(9, 7, 3))
+        .is_err());
+}
+
+#[test]
+fn test_large_game() {
+    let mut game = Game::with_rules(large(), 8);
+    for field in large().fields().into_iter().take(30) {
+        assert!(game.place_tile(field).is_ok());
+    }
+    let loaded = Game::load(&game.save()).unwrap();
+    assert_eq!(game.board, loaded.board);
+    assert!(game.save().contains("\nrules 1359 2467 348 r3\n"));
+
+    for field in large().fields().into_iter().skip(30) {
+        assert!(game.place_tile(field).is_ok());
+    }
+    assert!(game.finished());
+    assert_eq!(37, game.moves().len());
+    assert_eq!(11, game.board.remaining_tiles().len());
+    assert_eq!(21, game.board.line_scores().len());
+}
+
+#[test]
+fn test_parse_fields() {
+    assert_eq!(Ok(field!(2, 2)), Field::parse_with("center", &small()));
+    assert_eq!(
+        Ok(field!(3, 2)),
+        Field::parse_with("bottom right", &small())
+    );
+    assert_eq!(Ok(field!(4, 7)), Field::parse_with("bottom", &large()));
+    assert_eq!(Ok(field!(7, 1)), Field::parse_with("c7r1", &large()));
+    assert!(Field::parse_with("c7r1", &RuleSet::default()).is_err());
+
+    // ids only on boards with up to 26 fields
+    assert_eq!(Some('g'), field!(3, 2).id_with(&small()));
+    assert_eq!(Ok(field!(3, 2)), Field::parse_with("g", &small()));
+    assert_eq!(None, field!(1, 1).id_with(&large()));
+    assert!(Field::parse_with("a", &large()).is_err());
+}
+
+#[test]
+fn test_small_drawing() {
+    let mut board = Board::with_rules(small());
+    assert!(board.place_tile(field!(2, 2), tile!(5, 6, 4)).is_ok());
+    let expected = r"
+                       _______
+                      /       \
+              _______/         \_______
+             /       \         /       \
+            /         \_______/         \
+            \         /       \         /
+             \_______/    5    \_______/
+             /       \  6   4  /       \
+            /         \_______/         \
+            \         /       \         /
+             \_______/         \_______/
+                     \         /
+                      \_______/";
+    assert_eq!(expected, board.to_string());
+    assert_eq!(
+        Ok(board.clone()),
+        Board::from_ascii_with(expected, &small())
+    );
+    assert_eq!(
+        "--- --- | --- 564 --- | --- --- | score 0",
+        board.view(render::Style::Summary).to_string()
+    );
+}
+
+#[test]
+fn test_large_drawing() {
+    let board = play(large(), 2).board;
+    let text = board.to_string();
+    assert_eq!(30, text.lines().count());
+    assert_eq!(Ok(board.clone()), Board::from_ascii_with(&text, &large()));
+
+    let position = render::large_position_with(&field!(7, 4), &large());
+    assert_eq!(
+        Some(field!(7, 4)),
+        render::large_field_at_with(position.0, position.1, &large())
+    );
+}
+
+#[test]
+fn test_ai_on_small_board() {
+    let tiles: Vec<Tile> = small().tiles().into_iter().take(7).collect();
+    let (board, score) = Oracle::best_board_with(&tiles, &small()).unwrap();
+    assert!(board.is_full());
+    assert_eq!(board.score(), score);
+    assert!(score <= Solver::max_score_with(&small()));
+    assert!(Oracle::best_board_with(&tiles[..6], &small()).is_err());
+
+    let mut board = Board::with_rules(small());
+    assert!(board.place_tile(field!(1, 1), tiles[0]).is_ok());
+    let field = AI::best_field(&board, tiles[1], 1).unwrap();
+    assert!(board.empty_fields().contains(&field));
+}
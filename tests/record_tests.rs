@@ -0,0 +1,193 @@
+use record::{GameRecord, ParseError};
+use takeiteasy::*;
+
+const RECORD: &str = r#"[Date "2024-05-01"]
+[Players "Alice"]
+[Seed "42"]
+[Ruleset "classic"]
+
+{ league round 3 }
+973 C1R1
+924 C1R2 { going for nines }
+923 C1R3
+score 27
+"#;
+
+#[test]
+fn test_parse_record() {
+    let record = GameRecord::parse(RECORD).unwrap();
+    assert_eq!(Some("2024-05-01"), record.tag("date"));
+    assert_eq!(Some("Alice"), record.tag("Players"));
+    assert_eq!(Some("42"), record.tag("Seed"));
+    assert_eq!(Some("league round 3"), record.comment.as_deref());
+    assert_eq!(3, record.moves.len());
+    assert_eq!(tile!(9, 2, 4), record.moves[1].tile);
+    assert_eq!(field!(1, 2), record.moves[1].field);
+    assert_eq!(Some("going for nines"), record.moves[1].comment.as_deref());
+    assert_eq!(Some(27), record.score);
+}
+
+#[test]
+fn test_write_and_parse_again() {
+    let record = GameRecord::parse(RECORD).unwrap();
+    assert_eq!(RECORD, record.to_string());
+    assert_eq!(record, GameRecord::parse(&record.to_string()).unwrap());
+}
+
+#[test]
+fn test_record_to_board() {
+    let board = GameRecord::parse(RECORD).unwrap().to_board().unwrap();
+    assert_eq!(3, board.tiles.len());
+    assert_eq!(Some(&Some(tile!(9, 2, 3))), board.tiles.get(&field!(1, 3)));
+    assert_eq!(27, board.score());
+}
+
+#[cfg(feature = "entropy")]
+#[test]
+fn test_record_from_game() {
+    let mut game = Game::new();
+    for field in Board::all_fields() {
+        assert!(game.place_tile(field).is_ok());
+    }
+    let mut record = GameRecord::from_game(&game);
+    record.set_tag("Players", "Bob").unwrap();
+    assert_eq!(Some(game.board.score()), record.score);
+
+    let parsed = GameRecord::parse(&record.to_string()).unwrap();
+    let replayed = parsed.to_game().unwrap();
+    assert_eq!(game.seed(), replayed.seed());
+    assert!(replayed.finished());
+    assert_eq!(game.moves(), replayed.moves());
+    assert_eq!(game.board.score(), replayed.board.score());
+}
+
+#[test]
+fn test_seed_tag() {
+    let mut game = Game::with_seed(42);
+    for field in Board::all_fields().into_iter().take(5) {
+        assert!(game.place_tile(field).is_ok());
+    }
+    let record = GameRecord::from_game(&game);
+    assert_eq!(Some("42"), record.tag("Seed"));
+    assert_eq!(Ok(Some(42)), record.seed());
+
+    // the tiles after the moves are the ones of the seed
+    let parsed = GameRecord::parse(&record.to_string()).unwrap();
+    let mut replayed = parsed
+        .to_game_with_seed(parsed.seed().unwrap().unwrap())
+        .unwrap();
+    assert_eq!(42, replayed.seed());
+    for field in Board::all_fields().into_iter().skip(5) {
+        assert_eq!(game.current_tile, replayed.current_tile);
+        assert!(game.place_tile(field).is_ok());
+        assert!(replayed.place_tile(field).is_ok());
+    }
+
+    let mut record = GameRecord::new();
+    assert_eq!(Ok(None), record.seed());
+    assert!(record.set_tag("Seed", "x").is_ok());
+    assert_eq!(Err("invalid seed: x".to_string()), record.seed());
+}
+
+#[test]
+fn test_tag_names() {
+    let mut record = GameRecord::new();
+    assert!(record.set_tag("Event_2", "final").is_ok());
+    assert_eq!(
+        Err("invalid tag name: Round 2".to_string()),
+        record.set_tag("Round 2", "x")
+    );
+    assert!(record.set_tag("a]", "x").is_err());
+    assert!(record.set_tag("", "x").is_err());
+    assert_eq!(1, record.tags.len());
+
+    // set directly, a name that could not be read back
+    record.tags.push(("a]".to_string(), "x".to_string()));
+    assert!(std::fmt::write(&mut String::new(), format_args!("{}", record)).is_err());
+    assert_eq!(
+        "line 1, column 2: invalid tag name: a\"",
+        GameRecord::parse("[a\" \"x\"]").unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = |line, column, message: &str| ParseError {
+        line,
+        column,
+        message: message.to_string(),
+    };
+    assert_eq!(
+        Err(error(2, 5, "invalid field: C6R1")),
+        GameRecord::parse("973 C1R1\n924 C6R1")
+    );
+    assert_eq!(
+        Err(error(1, 1, "invalid left number: 3")),
+        GameRecord::parse("933 C1R1")
+    );
+    assert_eq!(
+        Err(error(1, 5, "missing field after the tile")),
+        GameRecord::parse("973 ")
+    );
+    assert_eq!(
+        Err(error(2, 1, "tags must come before the moves")),
+        GameRecord::parse("973 C1R1\n[Date \"today\"]")
+    );
+    assert_eq!(
+        Err(error(1, 10, "unterminated comment")),
+        GameRecord::parse("973 C1R1 { oops")
+    );
+    assert_eq!(
+        Err(error(2, 1, "text after the score")),
+        GameRecord::parse("score 3\n973 C1R1")
+    );
+    assert_eq!(
+        "line 1, column 7: tag value must be quoted",
+        GameRecord::parse("[Date today]").unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn test_invalid_moves() {
+    let record = GameRecord::parse("973 C1R1\n924 C1R1").unwrap();
+    assert_eq!(
+        Err("move 2: cannot place 924 at C1R1".to_string()),
+        record.to_board().map(|_| ())
+    );
+    let record = GameRecord::parse("973 C1R1\n973 C1R2").unwrap();
+    assert_eq!(
+        Err("move 2: cannot place 973 at C1R2".to_string()),
+        record.to_game_with_seed(1).map(|_| ())
+    );
+}
+
+#[test]
+fn test_escaped_text() {
+    let mut record = GameRecord::parse("973 C1R1").unwrap();
+    record
+        .set_tag("Players", r#"Alice "the fox" \ Bob"#)
+        .unwrap();
+    record.comment = Some("a } brace\nand a second line".to_string());
+    record.moves[0].comment = Some(r"{ nested } \}".to_string());
+    let text = record.to_string();
+    assert!(text.starts_with(r#"[Players "Alice \"the fox\" \\ Bob"]"#));
+    assert!(text.contains(r"{ a \} brace\nand a second line }"));
+    assert_eq!(record, GameRecord::parse(&text).unwrap());
+
+    assert!(GameRecord::parse(r#"[Date "today\"]"#).is_err());
+    assert_eq!(
+        Some("}"),
+        GameRecord::parse(r"973 C1R1 { \} }").unwrap().moves[0]
+            .comment
+            .as_deref()
+    );
+}
+
+#[test]
+fn test_declared_score() {
+    let error = GameRecord::parse(&RECORD.replace("score 27", "score 28")).unwrap_err();
+    assert_eq!((10, 7), (error.line, error.column));
+    assert_eq!("score 28 does not match the moves: 27", error.message);
+    assert!(GameRecord::parse("973 C1R1\nscore 0").is_ok());
+    assert!(GameRecord::parse("973 C1R1\n924 C1R1\nscore 0").is_err());
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "std")]
+
+use render::Style;
+use takeiteasy::*;
+
+fn get_board() -> Board {
+    let mut board = Board::new();
+    assert!(board.place_tile(field!(1, 1), tile!(9, 7, 3)).is_ok());
+    assert!(board.place_tile(field!(3, 1), tile!(1, 6, 3)).is_ok());
+    assert!(board.place_tile(field!(5, 3), tile!(5, 2, 8)).is_ok());
+    board
+}
+
+#[test]
+fn test_large_style_is_display() {
+    let board = get_board();
+    assert_eq!(board.to_string(), board.view(Style::Large).to_string());
+    assert_eq!(board.to_string(), board.view(Style::default()).to_string());
+}
+
+#[test]
+fn test_empty_field_entry() {
+    // a field mapped to no tile is drawn as empty instead of panicking
+    let mut board = get_board();
+    board.tiles.insert(field!(2, 2), None);
+    for style in [Style::Large, Style::Compact, Style::Unicode, Style::Summary] {
+        let mut empty = get_board();
+        empty.tiles.remove(&field!(2, 2));
+        assert_eq!(empty.view(style).to_string(), board.view(style).to_string());
+    }
+}
+
+#[test]
+fn test_compact_style() {
+    let text = get_board().view(Style::Compact).to_string();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(9, lines.len());
+    assert!(lines.iter().all(|line| line.chars().count() <= 80));
+    assert_eq!("            (163)", lines[0]);
+    assert!(lines[2].starts_with("(973)"));
+    assert!(lines[6].ends_with("(528)"));
+}
+
+#[test]
+fn test_unicode_style() {
+    let text = get_board().view(Style::Unicode).to_string();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(20, lines.len());
+    assert_eq!("                ╭─────╮", lines[0]);
+    assert_eq!("                │  1  │", lines[1]);
+    assert!(lines[2].contains("│ 6 3 │"));
+    assert!(!text.contains('╔'));
+}
+
+#[test]
+fn test_summary_style() {
+    assert_eq!(
+        "973 --- --- | --- --- --- --- | 163 --- --- --- --- | --- --- --- --- | --- --- 528 | score 0",
+        get_board().view(Style::Summary).to_string()
+    );
+}
+
+#[test]
+fn test_highlight_field() {
+    let board = get_board();
+    let large = board
+        .view(Style::Large)
+        .highlight_field(field!(1, 1))
+        .to_string();
+    assert!(large.lines().nth(6).unwrap().contains("/*******\\"));
+    assert_eq!(1, large.matches("*******").count());
+
+    let compact = board
+        .view(Style::Compact)
+        .highlight_field(field!(1, 1))
+        .to_string();
+    assert!(compact.contains("[973]"));
+
+    let unicode = board
+        .view(Style::Unicode)
+        .highlight_field(field!(1, 1))
+        .to_string();
+    assert_eq!(1, unicode.matches('╔').count());
+
+    let summary = board
+        .view(Style::Summary)
+        .highlight_field(field!(1, 1))
+        .to_string();
+    assert!(summary.starts_with("[973] ---"));
+}
+
+#[test]
+fn test_highlight_line() {
+    let board = get_board();
+    let line = &board.line_scores()[0];
+    let compact = board
+        .view(Style::Compact)
+        .highlight_line(&line.fields)
+        .to_string();
+    assert_eq!(line.fields.len(), compact.matches('[').count());
+}
+
+#[test]
+fn test_labels() {
+    let text = get_board().view(Style::Large).labels(true).to_string();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!("C3", lines[0].trim());
+    for label in ["C1", "C2", "C4", "C5"] {
+        assert_eq!(1, text.matches(label).count());
+    }
+    // rows count within a column, they are only in the coordinates
+    assert!(!text.contains('R'));
+    // ids and coordinates of empty fields, numbers of the others
+    assert!(lines[5].contains(" d "));
+    assert!(text.contains(" 3,2 "));
+    assert!(!text.contains(" 1,1 "));
+    assert!(!text.contains(" a "));
+    // the labeled drawing reads back
+    assert_eq!(Ok(get_board()), Board::from_ascii(&text));
+
+    let compact = get_board().view(Style::Compact).labels(true).to_string();
+    assert!(compact.contains("(i  )"));
+    assert!(compact.contains("(973)"));
+}
+
+#[test]
+fn test_large_field_at() {
+    for field in Board::all_fields() {
+        let (line, column) = render::large_position(&field);
+        assert_eq!(Some(field), render::large_field_at(line, column));
+        assert_eq!(Some(field), render::large_field_at(line + 1, column - 2));
+        assert_eq!(Some(field), render::large_field_at(line - 1, column + 3));
+    }
+    // frame and outside of the board
+    assert_eq!(None, render::large_field_at(0, 0));
+    assert_eq!(None, render::large_field_at(7, 12));
+}
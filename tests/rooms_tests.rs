@@ -0,0 +1,230 @@
+#![cfg(feature = "server")]
+
+use rooms::RoomServer;
+use serde_json::{json, Value};
+use std::net::TcpStream;
+use std::thread;
+use takeiteasy::*;
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::{Message, WebSocket};
+
+type Client = WebSocket<MaybeTlsStream<TcpStream>>;
+
+fn start() -> u16 {
+    let server = RoomServer::bind("127.0.0.1:0").unwrap();
+    let port = server.port().unwrap();
+    thread::spawn(move || server.run());
+    port
+}
+
+fn connect(port: u16) -> Client {
+    tungstenite::connect(format!("ws://127.0.0.1:{}/", port))
+        .unwrap()
+        .0
+}
+
+fn send(client: &mut Client, message: Value) {
+    client.send(Message::Text(message.to_string())).unwrap();
+}
+
+fn receive(client: &mut Client) -> Value {
+    loop {
+        if let Message::Text(text) = client.read().unwrap() {
+            return serde_json::from_str(&text).unwrap();
+        }
+    }
+}
+
+fn join(port: u16, room: &str, player: &str) -> Client {
+    let mut client = connect(port);
+    send(
+        &mut client,
+        json!({ "type": "join", "room": room, "player": player }),
+    );
+    assert_eq!("joined", receive(&mut client)["event"]);
+    client
+}
+
+fn place(client: &mut Client, field: Field) {
+    send(client, json!({ "type": "place", "field": field }));
+}
+
+#[test]
+fn test_room_game() {
+    let port = start();
+    let mut alice = join(port, "friday", "alice");
+    let mut bob = join(port, "friday", "bob");
+    let joined = receive(&mut alice);
+    assert_eq!(json!(["alice", "bob"]), joined["players"]);
+
+    send(&mut alice, json!({ "type": "start", "seed": 11 }));
+    let game = Game::with_seed(11);
+    for client in [&mut alice, &mut bob] {
+        let started = receive(client);
+        assert_eq!("started", started["event"]);
+        assert_eq!(11, started["seed"]);
+        let tile = receive(client);
+        assert_eq!("tile", tile["event"]);
+        assert_eq!(0, tile["round"]);
+        assert_eq!(json!(game.current_tile), tile["tile"]);
+    }
+
+    place(&mut alice, field!(3, 3));
+    for client in [&mut alice, &mut bob] {
+        let placed = receive(client);
+        assert_eq!("placed", placed["event"]);
+        assert_eq!("alice", placed["player"]);
+        assert_eq!(json!(["bob"]), placed["waiting_for"]);
+    }
+    place(&mut alice, field!(1, 1));
+    let error = receive(&mut alice);
+    assert_eq!("error", error["event"]);
+    assert_eq!(
+        "alice already placed the tile of this round",
+        error["message"]
+    );
+
+    // nobody can join a started game
+    let mut carol = connect(port);
+    send(
+        &mut carol,
+        json!({ "type": "join", "room": "friday", "player": "carol" }),
+    );
+    assert_eq!("error", receive(&mut carol)["event"]);
+
+    place(&mut bob, field!(3, 3));
+    for client in [&mut alice, &mut bob] {
+        assert_eq!("placed", receive(client)["event"]);
+        assert_eq!(json!({ "event": "ready", "round": 0 }), receive(client));
+        let tile = receive(client);
+        assert_eq!(1, tile["round"]);
+    }
+
+    for field in Board::all_fields()
+        .into_iter()
+        .filter(|f| *f != field!(3, 3))
+    {
+        place(&mut alice, field);
+        place(&mut bob, field);
+        for client in [&mut alice, &mut bob] {
+            assert_eq!("placed", receive(client)["event"]);
+            assert_eq!("placed", receive(client)["event"]);
+            assert_eq!("ready", receive(client)["event"]);
+            let next = receive(client);
+            if next["event"] == "game_over" {
+                let standings = next["standings"].as_array().unwrap();
+                assert_eq!(2, standings.len());
+                assert_eq!(standings[0]["score"], standings[1]["score"]);
+            } else {
+                assert_eq!("tile", next["event"]);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_room_errors() {
+    let port = start();
+    let mut client = connect(port);
+    let errors = [
+        json!({ "type": "start" }),
+        json!({ "type": "dance" }),
+        json!({ "room": "a" }),
+        json!({ "type": "join", "room": "a", "player": "" }),
+    ];
+    for message in errors {
+        send(&mut client, message);
+        assert_eq!("error", receive(&mut client)["event"]);
+    }
+    client.send(Message::Text("{".to_string())).unwrap();
+    assert_eq!("error", receive(&mut client)["event"]);
+
+    let mut alice = join(port, "a", "alice");
+    send(
+        &mut alice,
+        json!({ "type": "place", "field": { "column": 1, "row": 1 } }),
+    );
+    assert_eq!("the game has not started", receive(&mut alice)["message"]);
+    send(
+        &mut client,
+        json!({ "type": "join", "room": "a", "player": "alice" }),
+    );
+    assert_eq!("error", receive(&mut client)["event"]);
+
+    // a closed connection leaves the room
+    let mut bob = join(port, "a", "bob");
+    assert_eq!("joined", receive(&mut alice)["event"]);
+    bob.close(None).unwrap();
+    let left = receive(&mut alice);
+    assert_eq!("left", left["event"]);
+    assert_eq!(json!(["alice"]), left["players"]);
+}
+
+#[test]
+fn test_leave_during_game() {
+    let port = start();
+    let mut alice = join(port, "monday", "alice");
+    let mut bob = join(port, "monday", "bob");
+    assert_eq!("joined", receive(&mut alice)["event"]);
+
+    // only the host starts the game
+    send(&mut bob, json!({ "type": "start" }));
+    assert_eq!(
+        "only alice can start the game",
+        receive(&mut bob)["message"]
+    );
+
+    send(&mut alice, json!({ "type": "start", "seed": 3 }));
+    for client in [&mut alice, &mut bob] {
+        assert_eq!("started", receive(client)["event"]);
+        assert_eq!("tile", receive(client)["event"]);
+    }
+    place(&mut alice, field!(3, 3));
+    for client in [&mut alice, &mut bob] {
+        assert_eq!(json!(["bob"]), receive(client)["waiting_for"]);
+    }
+
+    // alice does not wait for bob anymore
+    bob.close(None).unwrap();
+    let left = receive(&mut alice);
+    assert_eq!("left", left["event"]);
+    assert_eq!(json!(["alice"]), left["players"]);
+    assert_eq!(json!({ "event": "ready", "round": 0 }), receive(&mut alice));
+    assert_eq!(1, receive(&mut alice)["round"]);
+
+    place(&mut alice, field!(1, 1));
+    assert_eq!("placed", receive(&mut alice)["event"]);
+    assert_eq!(json!({ "event": "ready", "round": 1 }), receive(&mut alice));
+}
+
+#[test]
+fn test_room_with_rules() {
+    let port = start();
+    let mut alice = join(port, "sunday", "alice");
+    let rules =
+        json!({ "top": [1, 3, 5, 9], "left": [2, 4, 6, 7], "right": [3, 4, 8], "radius": 3 });
+    send(
+        &mut alice,
+        json!({ "type": "start", "seed": 2, "rules": rules }),
+    );
+    assert_eq!(rules, receive(&mut alice)["rules"]);
+    let rules: rules::RuleSet = serde_json::from_value(rules).unwrap();
+    assert_eq!(
+        json!(Game::with_rules(rules, 2).current_tile),
+        receive(&mut alice)["tile"]
+    );
+
+    // a field only the large board has
+    send(
+        &mut alice,
+        json!({ "type": "place", "field": { "column": 7, "row": 1 } }),
+    );
+    assert_eq!("placed", receive(&mut alice)["event"]);
+    assert_eq!("ready", receive(&mut alice)["event"]);
+    assert_eq!("tile", receive(&mut alice)["event"]);
+    send(
+        &mut alice,
+        json!({ "type": "place", "field": { "column": 8, "row": 1 } }),
+    );
+    assert_eq!("error", receive(&mut alice)["event"]);
+}
@@ -0,0 +1,175 @@
+#[cfg(feature = "std")]
+use ai::AI;
+use record::GameRecord;
+use rules::RuleSet;
+use takeiteasy::*;
+use tile::Tile;
+
+fn variant() -> RuleSet {
+    RuleSet::new([1, 5, 6], [2, 7, 9], [3, 4, 8]).unwrap()
+}
+
+// `tile!` only builds classic tiles
+fn any_tile(top: u32, left: u32, right: u32) -> Tile {
+    Tile { top, left, right }
+}
+
+fn play(rules: RuleSet, seed: u64) -> Game {
+    let mut game = Game::with_rules(rules, seed);
+    for field in Board::all_fields() {
+        assert!(game.place_tile(field).is_ok());
+    }
+    game
+}
+
+#[test]
+fn test_classic_rules() {
+    let rules = RuleSet::default();
+    assert!(rules.is_classic());
+    assert_eq!(RuleSet::CLASSIC, rules);
+    assert_eq!(TileReservoir::all_tiles(), rules.tiles());
+    assert_eq!(9, rules.max_number(&tile::Direction::Top));
+    assert_eq!(Board::new().rules(), &rules);
+}
+
+#[test]
+fn test_classic_tiles() {
+    assert_eq!(Ok(tile!(9, 7, 3)), RuleSet::CLASSIC.tile(9, 7, 3));
+    assert_eq!(
+        Err("invalid left number: 3".to_string()),
+        RuleSet::CLASSIC.tile(9, 3, 7)
+    );
+    assert!(variant().tile(6, 9, 3).is_ok());
+    assert!(std::panic::catch_unwind(|| tile!(9, 3, 7)).is_err());
+
+    #[allow(deprecated)]
+    {
+        use tile::{NumLeft, NumRight, NumTop};
+        assert_eq!(9, tile::max_number(&tile::Direction::Top));
+        assert_eq!(Ok(NumTop::N5), NumTop::from_int(5));
+        assert_eq!(7, NumLeft::from_int(7).unwrap() as u32);
+        assert!(NumRight::from_int(7).is_err());
+        assert_eq!(8, u32::from(NumRight::N8));
+        assert_eq!(
+            tile!(5, 6, 4),
+            tile::Tile::classic(NumTop::N5, NumLeft::N6, NumRight::N4)
+        );
+        let tile = tile::Tile {
+            top: NumTop::N1.into(),
+            left: NumLeft::N2.into(),
+            right: NumRight::N3.into(),
+        };
+        assert_eq!(tile!(1, 2, 3), tile);
+    }
+}
+
+#[test]
+fn test_new_rules() {
+    let rules = RuleSet::new([9, 1, 5], [7, 2, 6], [3, 8, 4]).unwrap();
+    assert!(rules.is_classic());
+    assert_eq!(27, variant().tiles().len());
+    assert!(variant().contains(&any_tile(6, 9, 3)));
+    assert!(!variant().contains(&tile!(9, 7, 3)));
+
+    assert_eq!(
+        Err("invalid left number: 10".to_string()),
+        RuleSet::new([1, 5, 9], [2, 6, 10], [3, 4, 8])
+    );
+    assert_eq!(
+        Err("invalid right number: 0".to_string()),
+        RuleSet::new([1, 5, 9], [2, 6, 7], [0, 4, 8])
+    );
+    assert_eq!(
+        Err("top numbers must differ".to_string()),
+        RuleSet::new([5, 1, 5], [2, 6, 7], [3, 4, 8])
+    );
+}
+
+#[test]
+fn test_parse_rules() {
+    assert_eq!(Ok(RuleSet::CLASSIC), "classic".parse());
+    assert_eq!(Ok(RuleSet::CLASSIC), "951 267 843".parse());
+    assert_eq!("156 279 348", variant().to_string());
+    assert_eq!(Ok(variant()), variant().to_string().parse());
+
+    assert!("159 267".parse::<RuleSet>().is_err());
+    assert!("159 267 348 1".parse::<RuleSet>().is_err());
+    assert!("159 2x7 348".parse::<RuleSet>().is_err());
+    assert_eq!(
+        Err("invalid top number: 0".to_string()),
+        "150 267 348".parse::<RuleSet>()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_variant_board() {
+    let mut board = Board::with_rules(variant());
+    assert!(Board::new()
+        .place_tile(field!(1, 1), any_tile(6, 2, 3))
+        .is_err());
+    assert!(board.place_tile(field!(1, 1), tile!(9, 7, 3)).is_err());
+
+    // (6 + 9 + 8) * 19 fields
+    assert_eq!(437, board.max_score());
+    assert!(board.place_tile(field!(1, 1), any_tile(6, 2, 3)).is_ok());
+    assert!(board.place_tile(field!(1, 2), any_tile(6, 7, 4)).is_ok());
+    assert!(board.place_tile(field!(1, 3), any_tile(6, 9, 8)).is_ok());
+    assert_eq!(6 * 3, board.score());
+    assert_eq!(24, board.remaining_tiles().len());
+    assert!(board
+        .remaining_tiles()
+        .iter()
+        .all(|t| variant().contains(t)));
+
+    let field = AI::best_field(&board, tile!(1, 2, 4), 0).unwrap();
+    assert!(board.empty_fields().contains(&field));
+}
+
+#[test]
+fn test_variant_game() {
+    let game = play(variant(), 3);
+    assert!(game.finished());
+    assert_eq!(&variant(), game.rules());
+    assert!(game
+        .moves()
+        .iter()
+        .all(|(tile, _)| variant().contains(tile)));
+}
+
+#[test]
+fn test_variant_record() {
+    let game = play(variant(), 3);
+    let record = GameRecord::from_game(&game);
+    assert_eq!(Some("156 279 348"), record.tag("Ruleset"));
+
+    let parsed = GameRecord::parse(&record.to_string()).unwrap();
+    assert_eq!(Ok(variant()), parsed.rules());
+    assert_eq!(game.board, parsed.to_board().unwrap());
+    assert!(GameRecord::from_game(&play(RuleSet::default(), 3))
+        .tag("Ruleset")
+        .is_none());
+
+    // a classic tile in the variant
+    let error = GameRecord::parse("[Ruleset \"156 279 348\"]\n973 C1R1\n").unwrap_err();
+    assert_eq!((2, 1), (error.line, error.column));
+    assert_eq!("invalid top number: 9", error.message);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_variant_save() {
+    let mut game = Game::with_rules(variant(), 11);
+    for field in Board::all_fields().into_iter().take(4) {
+        assert!(game.place_tile(field).is_ok());
+    }
+    let text = game.save();
+    assert!(text.contains("\nrules 156 279 348\n"));
+    let loaded = Game::load(&text).unwrap();
+    assert_eq!(game.board, loaded.board);
+    assert_eq!(text, loaded.save());
+
+    // the rules decide which tiles are valid
+    let classic = text.replace("rules 156 279 348\n", "");
+    assert!(Game::load(&classic).is_err());
+}
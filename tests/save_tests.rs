@@ -0,0 +1,71 @@
+#![cfg(feature = "std")]
+
+use takeiteasy::*;
+
+fn get_game() -> Game {
+    let mut game = Game::with_seed(7);
+    for field in Board::all_fields().into_iter().take(5) {
+        assert!(game.place_tile(field).is_ok());
+    }
+    game
+}
+
+#[test]
+fn test_save_and_load() {
+    let game = get_game();
+    let text = game.save();
+    assert!(text.starts_with(&format!("takeiteasy-save {}\n", SAVE_VERSION)));
+
+    let loaded = Game::load(&text).unwrap();
+    assert_eq!(game.board, loaded.board);
+    assert_eq!(game.current_tile, loaded.current_tile);
+    assert_eq!(game.moves(), loaded.moves());
+    assert_eq!(game.seed(), loaded.seed());
+    assert_eq!(text, loaded.save());
+}
+
+#[test]
+fn test_loaded_game_draws_the_same_tiles() {
+    let mut game = get_game();
+    let mut loaded = Game::load(&game.save()).unwrap();
+    for field in Board::all_fields().into_iter().skip(5) {
+        assert!(game.place_tile(field).is_ok());
+        assert!(loaded.place_tile(field).is_ok());
+        assert_eq!(game.current_tile, loaded.current_tile);
+    }
+    assert!(loaded.finished());
+    assert_eq!(game.board.score(), loaded.board.score());
+}
+
+#[test]
+fn test_load_errors() {
+    let text = get_game().save();
+    assert_eq!(
+        Err("not a save file".to_string()),
+        Game::load("973 C1R1").map(|_| ())
+    );
+    assert_eq!(
+        Err("unsupported version: 99".to_string()),
+        Game::load(&text.replacen(" 1\n", " 99\n", 1)).map(|_| ())
+    );
+    assert_eq!(
+        Err("missing seed".to_string()),
+        Game::load(&text.replace("seed 7\n", "")).map(|_| ())
+    );
+    assert_eq!(
+        Err("line 2: invalid seed: x".to_string()),
+        Game::load(&text.replace("seed 7", "seed x")).map(|_| ())
+    );
+    assert_eq!(
+        Err("line 3: unknown entry: color".to_string()),
+        Game::load(&text.replace("seed 7\n", "seed 7\ncolor red\n")).map(|_| ())
+    );
+
+    // a tile both placed and remaining
+    let tile = record::format_tile(&get_game().moves()[0].0);
+    let duplicate = text.replace("remaining ", &format!("remaining {} ", tile));
+    assert_eq!(
+        Err("tiles of the game are incomplete".to_string()),
+        Game::load(&duplicate).map(|_| ())
+    );
+}
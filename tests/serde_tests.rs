@@ -0,0 +1,156 @@
+#![cfg(feature = "serde")]
+
+use rules::RuleSet;
+use takeiteasy::*;
+use tile::Tile;
+
+#[test]
+fn test_tile_json() {
+    assert_eq!("[9,7,3]", serde_json::to_string(&tile!(9, 7, 3)).unwrap());
+    assert_eq!(
+        tile!(9, 7, 3),
+        serde_json::from_str::<Tile>("[9, 7, 3]").unwrap()
+    );
+    assert!(serde_json::from_str::<Tile>("[9, 3, 7]").is_err());
+    assert!(serde_json::from_str::<Tile>("[9, 0, 7]").is_err());
+    assert!(serde_json::from_str::<Tile>("[9, 7]").is_err());
+}
+
+#[test]
+fn test_field_json() {
+    assert_eq!(
+        r#"{"column":3,"row":2}"#,
+        serde_json::to_string(&field!(3, 2)).unwrap()
+    );
+    assert_eq!(
+        field!(3, 2),
+        serde_json::from_str::<Field>(r#"{"column": 3, "row": 2}"#).unwrap()
+    );
+    let error = serde_json::from_str::<Field>(r#"{"column": 1, "row": 4}"#).unwrap_err();
+    assert!(error.to_string().contains("invalid coordinates: 1, 4"));
+    assert!(serde_json::from_str::<Field>(r#"{"column": 6, "row": 1}"#).is_err());
+}
+
+#[test]
+fn test_board_json() {
+    let mut board = Board::new();
+    assert!(board.place_tile(field!(3, 2), tile!(1, 2, 3)).is_ok());
+    assert!(board.place_tile(field!(1, 1), tile!(9, 7, 8)).is_ok());
+
+    let json = serde_json::to_string(&board).unwrap();
+    assert_eq!(
+        r#"{"tiles":[{"field":{"column":1,"row":1},"tile":[9,7,8]},{"field":{"column":3,"row":2},"tile":[1,2,3]}]}"#,
+        json
+    );
+    let parsed: Board = serde_json::from_str(&json).unwrap();
+    assert_eq!(board.tiles, parsed.tiles);
+
+    // same field twice
+    let json = r#"{"tiles":[{"field":{"column":1,"row":1},"tile":[9,7,8]},{"field":{"column":1,"row":1},"tile":[1,2,3]}]}"#;
+    assert!(serde_json::from_str::<Board>(json).is_err());
+    // same tile twice
+    let json = r#"{"tiles":[{"field":{"column":1,"row":1},"tile":[9,7,8]},{"field":{"column":1,"row":2},"tile":[9,7,8]}]}"#;
+    assert!(serde_json::from_str::<Board>(json).is_err());
+    // not a tile of the classic game
+    let json = r#"{"tiles":[{"field":{"column":1,"row":1},"tile":[9,3,7]}]}"#;
+    assert!(serde_json::from_str::<Board>(json).is_err());
+}
+
+#[test]
+fn test_variant_board_json() {
+    let rules = RuleSet::new([2, 5, 9], [1, 6, 7], [3, 4, 8]).unwrap();
+    let mut board = Board::with_rules(rules);
+    let tile = rules.tile(2, 1, 3).unwrap();
+    assert!(board.place_tile(field!(1, 1), tile).is_ok());
+
+    let json = serde_json::to_string(&board).unwrap();
+    assert_eq!(
+        r#"{"rules":{"top":[2,5,9],"left":[1,6,7],"right":[3,4,8]},"tiles":[{"field":{"column":1,"row":1},"tile":[2,1,3]}]}"#,
+        json
+    );
+    let parsed: Board = serde_json::from_str(&json).unwrap();
+    assert_eq!(board, parsed);
+}
+
+#[test]
+fn test_radius_board_json() {
+    let rules = RuleSet::from_toml(
+        "top = [1, 3, 5, 9]\nleft = [2, 4, 6, 7]\nright = [3, 4, 8]\nradius = 3\n",
+    )
+    .unwrap();
+    assert_eq!(37, rules.num_fields());
+    let mut board = Board::with_rules(rules);
+    let tile = rules.tile(3, 4, 8).unwrap();
+    assert!(board.place_tile(field!(7, 4), tile).is_ok());
+
+    let json = serde_json::to_string(&board).unwrap();
+    assert!(json.contains(r#""radius":3"#));
+    let parsed: Board = serde_json::from_str(&json).unwrap();
+    assert_eq!(board, parsed);
+
+    // the field is not on the classic board
+    let classic = json.replace(r#""radius":3"#, r#""radius":2"#);
+    let error = serde_json::from_str::<Board>(&classic).unwrap_err();
+    assert!(error.to_string().contains("invalid coordinates: 7, 4"));
+}
+
+#[test]
+fn test_rule_set_files() {
+    let toml = "top = [2, 5, 9]\nleft = [1, 6, 7]\nright = [8, 4, 3]\n";
+    let rules = RuleSet::from_toml(toml).unwrap();
+    assert_eq!("259 167 348", rules.to_string());
+    let json = r#"{"top": [2, 5, 9], "left": [1, 6, 7], "right": [3, 4, 8]}"#;
+    assert_eq!(rules, RuleSet::from_json(json).unwrap());
+
+    let error = RuleSet::from_json(r#"{"top": [1, 5, 5], "left": [2, 6, 7], "right": [3, 4, 8]}"#)
+        .unwrap_err();
+    assert!(error.contains("top numbers must differ"));
+    assert!(RuleSet::from_toml("top = [1, 5, 9]").is_err());
+
+    let path = std::env::temp_dir().join(format!("takeiteasy-rules-{}.json", std::process::id()));
+    std::fs::write(&path, json).unwrap();
+    assert_eq!(Ok(rules), RuleSet::load(&path));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_tile_reservoir_json() {
+    let mut reservoir = TileReservoir::new();
+    assert!(reservoir.pick_tile(&tile!(1, 2, 3)).is_ok());
+    let json = serde_json::to_string(&reservoir).unwrap();
+    assert!(json.starts_with(r#"{"remaining_tiles":[[1,2,4],[1,2,8],"#));
+    let parsed: TileReservoir = serde_json::from_str(&json).unwrap();
+    assert_eq!(reservoir.remaining_tiles, parsed.remaining_tiles);
+
+    assert!(
+        serde_json::from_str::<TileReservoir>(r#"{"remaining_tiles":[[1,2,4],[1,2,4]]}"#).is_err()
+    );
+}
+
+#[test]
+fn test_game_json() {
+    let mut game = Game::with_seed(42);
+    for field in Board::all_fields().into_iter().take(5) {
+        assert!(game.place_tile(field).is_ok());
+    }
+    let json = serde_json::to_string(&game).unwrap();
+    let mut parsed: Game = serde_json::from_str(&json).unwrap();
+    assert_eq!(game.board.tiles, parsed.board.tiles);
+    assert_eq!(game.current_tile, parsed.current_tile);
+    assert_eq!(game.moves(), parsed.moves());
+    assert_eq!(42, parsed.seed());
+    assert_eq!(json, serde_json::to_string(&parsed).unwrap());
+
+    // the same tiles are drawn after the round trip
+    for field in Board::all_fields().into_iter().skip(5) {
+        assert!(game.place_tile(field).is_ok());
+        assert!(parsed.place_tile(field).is_ok());
+        assert_eq!(game.current_tile, parsed.current_tile);
+    }
+    assert_eq!(game.moves(), parsed.moves());
+
+    // the current tile is also on the board
+    let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    value["current_tile"] = value["moves"][0]["tile"].clone();
+    assert!(serde_json::from_value::<Game>(value).is_err());
+}
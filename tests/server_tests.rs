@@ -0,0 +1,191 @@
+#![cfg(feature = "server")]
+
+use serde_json::{json, Value};
+use server::Server;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::thread;
+use takeiteasy::*;
+
+fn start() -> u16 {
+    let server = Server::bind("127.0.0.1:0").unwrap();
+    let port = server.port().unwrap();
+    thread::spawn(move || server.run());
+    port
+}
+
+fn request(port: u16, method: &str, path: &str, body: Option<Value>) -> (u16, Value) {
+    let body = body.map(|b| b.to_string()).unwrap_or_default();
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+        method,
+        path,
+        body.len(),
+        body
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+    (status, serde_json::from_str(body).unwrap())
+}
+
+#[test]
+fn test_game_flow() {
+    let port = start();
+    let (status, state) = request(
+        port,
+        "POST",
+        "/games",
+        Some(json!({ "players": ["alice", "bob"], "seed": 5 })),
+    );
+    assert_eq!(201, status);
+    let id = state["id"].as_u64().unwrap();
+    assert_eq!(5, state["seed"]);
+    assert_eq!(
+        serde_json::to_value(Game::with_seed(5).current_tile).unwrap(),
+        state["current_tile"]
+    );
+    assert_eq!(json!(["alice", "bob"]), state["waiting_for"]);
+
+    let place = |player: &str, column: i32, row: i32| {
+        request(
+            port,
+            "POST",
+            &format!("/games/{}/place", id),
+            Some(json!({ "player": player, "field": { "column": column, "row": row } })),
+        )
+    };
+    let (status, state) = place("alice", 3, 3);
+    assert_eq!(200, status);
+    assert_eq!(json!(["bob"]), state["waiting_for"]);
+    assert_eq!(0, state["round"]);
+
+    let (status, state) = place("alice", 1, 1);
+    assert_eq!(409, status);
+    assert!(state["error"].as_str().unwrap().contains("already placed"));
+
+    let (status, state) = request(
+        port,
+        "POST",
+        &format!("/games/{}/ai-move", id),
+        Some(json!({ "player": "bob", "depth": 0 })),
+    );
+    assert_eq!(200, status);
+    assert_eq!(1, state["round"]);
+
+    let (status, hint) = request(
+        port,
+        "GET",
+        &format!("/games/{}/hint?player=alice&depth=0", id),
+        None,
+    );
+    assert_eq!(200, status);
+    assert_eq!(state["current_tile"], hint["tile"]);
+
+    let (status, lines) = request(port, "GET", &format!("/games/{}/lines", id), None);
+    assert_eq!(200, status);
+    assert_eq!(15, lines["players"][0]["lines"].as_array().unwrap().len());
+
+    let (_, games) = request(port, "GET", "/games", None);
+    assert!(games["games"].as_array().unwrap().contains(&json!(id)));
+    assert_eq!(
+        200,
+        request(port, "DELETE", &format!("/games/{}", id), None).0
+    );
+    assert_eq!(404, request(port, "GET", &format!("/games/{}", id), None).0);
+}
+
+#[test]
+fn test_errors() {
+    let port = start();
+    assert_eq!(404, request(port, "GET", "/games/x", None).0);
+    assert_eq!(404, request(port, "GET", "/nothing", None).0);
+    assert_eq!(
+        400,
+        request(port, "POST", "/games", Some(json!({ "players": [] }))).0
+    );
+
+    let (status, state) = request(port, "POST", "/games", None);
+    assert_eq!(201, status);
+    assert_eq!(json!(["player"]), state["waiting_for"]);
+    let id = state["id"].as_u64().unwrap();
+    let path = format!("/games/{}/place", id);
+    let (status, error) = request(
+        port,
+        "POST",
+        &path,
+        Some(json!({ "player": "player", "field": { "column": 9, "row": 9 } })),
+    );
+    assert_eq!(400, status);
+    assert!(error["error"].is_string());
+    let (status, _) = request(
+        port,
+        "POST",
+        &path,
+        Some(json!({ "player": "nobody", "field": { "column": 1, "row": 1 } })),
+    );
+    assert_eq!(409, status);
+    let (status, _) = request(
+        port,
+        "GET",
+        &format!("/games/{}/hint?player=player&depth=9", id),
+        None,
+    );
+    assert_eq!(400, status);
+    let (status, _) = request(
+        port,
+        "POST",
+        &format!("/games/{}/ai-move", id),
+        Some(json!({ "player": "player", "depth": 2 })),
+    );
+    assert_eq!(400, status);
+    let (status, _) = request(port, "GET", &format!("/games/{}/hint?player=%zz", id), None);
+    assert_eq!(400, status);
+}
+
+#[test]
+fn test_variant_rules() {
+    let port = start();
+    let rules =
+        json!({ "top": [1, 3, 5, 9], "left": [2, 4, 6, 7], "right": [3, 4, 8], "radius": 3 });
+    let (status, state) = request(
+        port,
+        "POST",
+        "/games",
+        Some(json!({ "players": ["alice b"], "seed": 5, "rules": rules })),
+    );
+    assert_eq!(201, status);
+    assert_eq!(rules, state["rules"]);
+    let id = state["id"].as_u64().unwrap();
+
+    let (status, state) = request(
+        port,
+        "POST",
+        &format!("/games/{}/place", id),
+        Some(json!({ "player": "alice b", "field": { "column": 7, "row": 1 } })),
+    );
+    assert_eq!(200, status);
+    assert_eq!(1, state["round"]);
+
+    let (status, hint) = request(
+        port,
+        "GET",
+        &format!("/games/{}/hint?player=alice%20b&depth=0", id),
+        None,
+    );
+    assert_eq!(200, status);
+    assert_eq!("alice b", hint["player"]);
+    assert_eq!(state["current_tile"], hint["tile"]);
+
+    let (status, _) = request(
+        port,
+        "POST",
+        "/games",
+        Some(json!({ "rules": { "top": [1], "left": [2], "right": [3] } })),
+    );
+    assert_eq!(400, status);
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "std")]
+
+use session::Session;
+use takeiteasy::*;
+
+fn names(names: &[&str]) -> Vec<String> {
+    names.iter().map(|n| n.to_string()).collect()
+}
+
+#[test]
+fn test_new_session() {
+    let session = Session::new(&names(&["alice", "bob"]), 7).unwrap();
+    assert_eq!(7, session.seed());
+    assert_eq!(0, session.round());
+    assert!(!session.finished());
+    assert_eq!(vec!["alice", "bob"], session.waiting_for());
+    assert_eq!(Game::with_seed(7).current_tile, session.current_tile());
+
+    assert!(Session::new(&[], 7).is_err());
+    assert!(Session::new(&names(&["alice", " "]), 7).is_err());
+    assert!(Session::new(&names(&["alice", "alice"]), 7).is_err());
+}
+
+#[test]
+fn test_rounds_in_lockstep() {
+    let mut session = Session::new(&names(&["alice", "bob"]), 7).unwrap();
+    let first = session.current_tile();
+
+    session.place("alice", field!(1, 1)).unwrap();
+    assert_eq!(0, session.round());
+    assert_eq!(first, session.current_tile());
+    assert_eq!(vec!["bob"], session.waiting_for());
+    assert!(session.place("alice", field!(1, 2)).is_err());
+    assert!(session.place("carol", field!(1, 2)).is_err());
+
+    assert!(session.place("bob", field!(3, 3)).is_ok());
+    assert!(session.place("bob", field!(3, 3)).is_err());
+    assert_eq!(1, session.round());
+    assert_eq!(vec!["alice", "bob"], session.waiting_for());
+    assert_eq!(
+        session.player("alice").unwrap().game.board.tiles[&field!(1, 1)],
+        first
+    );
+    assert_eq!(
+        session.player("bob").unwrap().game.board.tiles[&field!(3, 3)],
+        first
+    );
+}
+
+#[test]
+fn test_finished_session() {
+    let mut session = Session::new(&names(&["alice", "bob"]), 3).unwrap();
+    for field in Board::all_fields() {
+        session.place("alice", field).unwrap();
+        session.place("bob", field).unwrap();
+    }
+    assert!(session.finished());
+    assert_eq!(None, session.current_tile());
+    assert!(session.waiting_for().is_empty());
+    assert!(session.place("alice", field!(1, 1)).is_err());
+
+    // same tiles on the same fields
+    let standings = session.standings();
+    assert_eq!(standings[0].1, standings[1].1);
+}
+
+#[test]
+fn test_place_errors() {
+    let mut session = Session::new(&names(&["alice", "bob"]), 7).unwrap();
+    session.place("alice", field!(1, 1)).unwrap();
+    session.place("bob", field!(1, 1)).unwrap();
+    assert_eq!(
+        Err("Field(1 1) is not empty".to_string()),
+        session.place("alice", field!(1, 1))
+    );
+    let outside = Field {
+        column: Coordinate::C1,
+        row: Coordinate::C5,
+    };
+    assert_eq!(
+        Err("Field(1 5) is not on the board".to_string()),
+        session.place("alice", outside)
+    );
+    assert_eq!(
+        Err("unknown player: carol".to_string()),
+        session.place("carol", field!(1, 2))
+    );
+}
+
+#[test]
+fn test_remove_player() {
+    let mut session = Session::new(&names(&["alice", "bob"]), 7).unwrap();
+    session.place("alice", field!(1, 1)).unwrap();
+    assert_eq!(0, session.round());
+    assert_eq!("bob", session.remove("bob").unwrap().name);
+    // the round ended without bob
+    assert_eq!(1, session.round());
+    assert_eq!(vec!["alice"], session.waiting_for());
+    assert_eq!(
+        Err("unknown player: bob".to_string()),
+        session.remove("bob").map(|p| p.name)
+    );
+    assert_eq!(
+        Err("a game needs at least one player".to_string()),
+        session.remove("alice").map(|p| p.name)
+    );
+}
+
+#[test]
+fn test_session_with_rules() {
+    let rules = rules::RuleSet::with_radius(1, &[1, 5, 9], &[2, 6, 7], &[3, 4, 8]).unwrap();
+    let mut session = Session::with_rules(&names(&["alice"]), rules, 7).unwrap();
+    assert_eq!(&rules, session.rules());
+    assert_eq!(
+        Game::with_rules(rules, 7).current_tile,
+        session.current_tile()
+    );
+    for field in rules.fields() {
+        session.place("alice", field).unwrap();
+    }
+    assert!(session.finished());
+    assert_eq!(
+        Err("the game is finished".to_string()),
+        session.place("alice", field!(2, 2))
+    );
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "std")]
+
+use solver::Solver;
+use takeiteasy::*;
+
+fn rotated(field: &Field) -> Field {
+    let column_len = [3, 4, 5, 4, 3][field.column as usize - 1];
+    field!(6 - field.column as i32, column_len + 1 - field.row as i32)
+}
+
+#[test]
+fn test_max_score() {
+    assert_eq!(307, Solver::max_score());
+}
+
+#[test]
+fn test_best_boards() {
+    let boards = Solver::best_boards();
+    assert!(!boards.is_empty());
+    for board in &boards {
+        assert!(board.is_full());
+        assert_eq!(307, board.score());
+    }
+
+    // no board is listed twice, also not turned by 180°
+    for (i, board) in boards.iter().enumerate() {
+        for other in &boards[i + 1..] {
+            let same = Board::all_fields()
+                .iter()
+                .all(|field| board.tiles.get(field) == other.tiles.get(field));
+            let turned = Board::all_fields()
+                .iter()
+                .all(|field| board.tiles.get(field) == other.tiles.get(&rotated(field)));
+            assert!(!same && !turned);
+        }
+    }
+}
+
+#[test]
+fn test_reachable_scores() {
+    assert!(Solver::can_reach(0));
+    assert!(!Solver::can_reach(1));
+    assert!(!Solver::can_reach(2));
+    assert!(Solver::can_reach(3));
+    assert!(!Solver::can_reach(308));
+
+    for score in [45, 150, 222] {
+        let board = Solver::board_with_score(score).unwrap();
+        assert!(board.is_full());
+        assert_eq!(score, board.score());
+    }
+}
+
+#[test]
+fn test_variant_rules() {
+    let rules = rules::RuleSet::with_radius(1, &[1, 5, 9], &[2, 6, 7], &[3, 4, 8]).unwrap();
+    let max_score = Solver::max_score_with(&rules);
+    let boards = Solver::best_boards_with(&rules);
+    assert!(!boards.is_empty());
+    for board in &boards {
+        assert_eq!(&rules, board.rules());
+        assert!(board.is_full());
+        assert_eq!(max_score, board.score());
+    }
+
+    assert!(Solver::can_reach_with(0, &rules));
+    assert!(!Solver::can_reach_with(max_score + 1, &rules));
+    let board = Solver::board_with_score_with(max_score, &rules).unwrap();
+    assert_eq!(max_score, board.score());
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "render-svg")]
+
+use svg::SvgOptions;
+use takeiteasy::*;
+
+fn get_board() -> Board {
+    // left column scores 3 * 9
+    let mut board = Board::new();
+    assert!(board.place_tile(field!(1, 1), tile!(9, 7, 3)).is_ok());
+    assert!(board.place_tile(field!(1, 2), tile!(9, 2, 4)).is_ok());
+    assert!(board.place_tile(field!(1, 3), tile!(9, 2, 3)).is_ok());
+    assert!(board.place_tile(field!(3, 3), tile!(5, 6, 8)).is_ok());
+    board
+}
+
+#[test]
+fn test_svg_fields() {
+    let svg = get_board().to_svg(&SvgOptions::default());
+    assert!(svg.starts_with("<svg "));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert_eq!(19, svg.matches("<polygon").count());
+    // three bars per tile
+    assert_eq!(4 * 3, svg.matches("<line").count());
+    // yellow bar for the nines
+    assert!(svg.contains(r##"stroke="#fad728""##));
+}
+
+#[test]
+fn test_svg_field_labels() {
+    let svg = get_board().to_svg(&SvgOptions::default());
+    assert!(svg.contains(">2,1</text>"));
+    assert!(!svg.contains(">1,1</text>"));
+
+    let options = SvgOptions {
+        field_labels: false,
+        ..SvgOptions::default()
+    };
+    assert!(!get_board().to_svg(&options).contains(">2,1</text>"));
+}
+
+#[test]
+fn test_svg_completed_lines() {
+    let svg = get_board().to_svg(&SvgOptions::default());
+    assert_eq!(1, svg.matches("<polyline").count());
+
+    let options = SvgOptions {
+        highlight_lines: false,
+        ..SvgOptions::default()
+    };
+    assert_eq!(0, get_board().to_svg(&options).matches("<polyline").count());
+}
+
+#[test]
+fn test_svg_score_overlay() {
+    let options = SvgOptions {
+        score_overlay: true,
+        ..SvgOptions::default()
+    };
+    let svg = get_board().to_svg(&options);
+    assert!(svg.contains(">Score: 27</text>"));
+    assert!(svg.contains(">27</text>"));
+    assert!(!SvgOptions::default().score_overlay);
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn test_png() {
+    let png = get_board().to_png(&SvgOptions::default()).unwrap();
+    assert_eq!(b"\x89PNG", &png[..4]);
+}
@@ -97,11 +97,13 @@ fn test_pick_all_tiles() {
     assert!(reservoir.remaining_tiles.is_empty());
 }
 
+#[cfg(feature = "entropy")]
 #[test]
 fn test_get_random_tile() {
     assert!(TileReservoir::new().pick_random_tile().is_ok());
 }
 
+#[cfg(feature = "entropy")]
 #[test]
 fn test_pick_all_random_tiles() {
     let mut reservoir = TileReservoir::new();
@@ -111,6 +113,7 @@ fn test_pick_all_random_tiles() {
     assert!(reservoir.pick_random_tile().is_err());
 }
 
+#[cfg(feature = "entropy")]
 #[test]
 fn test_pick_random_tile_not_always_the_same() {
     let tries = 1000.0;
@@ -129,3 +132,21 @@ fn test_pick_random_tile_not_always_the_same() {
         errors / tries
     );
 }
+
+#[test]
+fn test_pick_random_tile_with() {
+    use rand::SeedableRng;
+
+    let mut reservoir = TileReservoir::new();
+    let mut other = TileReservoir::new();
+    let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+    let mut other_rng = rand::rngs::StdRng::seed_from_u64(1);
+    for _ in 0..27 {
+        assert_eq!(
+            reservoir.pick_random_tile_with(&mut rng),
+            other.pick_random_tile_with(&mut other_rng)
+        );
+    }
+    assert!(reservoir.remaining_tiles.is_empty());
+    assert_eq!(Err(()), reservoir.pick_random_tile_with(&mut rng));
+}
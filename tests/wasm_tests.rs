@@ -0,0 +1,94 @@
+#![cfg(feature = "wasm")]
+
+// also runs in a headless browser:
+//     wasm-pack test --headless --firefox -- --no-default-features --features wasm
+
+use takeiteasy::wasm::{parse_field, parse_tile_numbers, WasmBoard, WasmGame};
+use takeiteasy::*;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn test_game() {
+    let mut game = WasmGame::new(9);
+    let expected = Game::with_seed(9);
+    let tile = expected.current_tile.unwrap();
+    assert_eq!(9, game.seed());
+    assert_eq!(
+        Some(vec![tile.top, tile.left, tile.right]),
+        game.current_tile()
+    );
+    assert_eq!(27 * 3, game.remaining_tiles().len());
+
+    game.place_tile(3, 3).unwrap();
+    assert_eq!(1, game.move_count());
+    assert!(game.place_tile(3, 3).is_err());
+    assert!(game.place_tile(0, 3).is_err());
+    assert!(game.place_tile(1, 5).is_err());
+
+    let board = game.board();
+    assert_eq!(
+        Some(vec![tile.top, tile.left, tile.right]),
+        board.tile_at(3, 3).unwrap()
+    );
+    assert_eq!(None, board.tile_at(1, 1).unwrap());
+    assert_eq!(18 * 2, board.empty_fields().len());
+
+    let loaded = WasmGame::load(&game.save()).unwrap();
+    assert_eq!(game.current_tile(), loaded.current_tile());
+    assert!(WasmGame::load("nothing").is_err());
+}
+
+#[test]
+fn test_game_to_the_end() {
+    let mut game = WasmGame::new(4);
+    while !game.finished() {
+        let hint = game.hint(0).unwrap();
+        game.place_tile(hint[0], hint[1]).unwrap();
+    }
+    assert_eq!(None, game.current_tile());
+    assert!(game.hint(0).is_err());
+    assert!(game.place_tile(1, 1).is_err());
+    assert_eq!(game.board().score(), game.score());
+}
+
+#[test]
+fn test_board() {
+    let mut board = WasmBoard::new();
+    board.place_tile(1, 1, 9, 7, 3).unwrap();
+    assert!(board.place_tile(1, 2, 9, 7, 3).is_err());
+    assert!(board.place_tile(1, 2, 9, 7, 5).is_err());
+    // the field and the tile of variants, the bindings are classic only
+    assert!(board.place_tile(7, 1, 9, 7, 4).is_err());
+    assert!(board.place_tile(1, 2, 6, 9, 3).is_err());
+    assert!(board.place_tile(1, 1, 5, 2, 8).is_err());
+    assert_eq!(Some(vec![9, 7, 3]), board.tile_at(1, 1).unwrap());
+    assert!(board.tile_at(5, 5).is_err());
+    assert!(!board.is_full());
+    assert!(board.max_score() >= board.score());
+
+    let mut expected = Board::new();
+    expected.place_tile(field!(1, 1), tile!(9, 7, 3)).unwrap();
+    let best = ai::AI::best_field(&expected, tile!(9, 7, 4), 0).unwrap();
+    assert_eq!(
+        vec![best.column as i32, best.row as i32],
+        board.best_field(9, 7, 4, 0).unwrap()
+    );
+    assert!(board.best_field(9, 7, 3, 0).is_err());
+    assert_eq!(
+        Err("invalid depth: -1".to_string()),
+        board.best_field(9, 7, 4, -1)
+    );
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(vec![9, 7, 3], parse_tile_numbers("973").unwrap());
+    assert!(parse_tile_numbers("999").is_err());
+    assert_eq!(vec![3, 3], parse_field("center").unwrap());
+    assert_eq!(vec![3, 2], parse_field("C3R2").unwrap());
+    assert!(parse_field("C1R5").is_err());
+}